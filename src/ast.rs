@@ -0,0 +1,220 @@
+use std::fmt;
+
+use parser::Spanning;
+
+/// A type reference as written in a query, e.g. `Color!` or `[Int]`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Type {
+    Named(String),
+    List(Box<Type>),
+    NonNullNamed(String),
+    NonNullList(Box<Type>),
+}
+
+impl Type {
+    /// The name of the innermost named type.
+    pub fn innermost_name(&self) -> &str {
+        match *self {
+            Type::Named(ref n) | Type::NonNullNamed(ref n) => n,
+            Type::List(ref l) | Type::NonNullList(ref l) => l.innermost_name(),
+        }
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Type::Named(ref n) => write!(f, "{}", n),
+            Type::NonNullNamed(ref n) => write!(f, "{}!", n),
+            Type::List(ref t) => write!(f, "[{}]", t),
+            Type::NonNullList(ref t) => write!(f, "[{}]!", t),
+        }
+    }
+}
+
+/// An input value as parsed from a query or supplied through the variables map.
+#[derive(Clone, Debug, PartialEq)]
+pub enum InputValue {
+    Null,
+    Int(i64),
+    Float(f64),
+    String(String),
+    Boolean(bool),
+    Enum(String),
+    Variable(String),
+    List(Vec<Spanning<InputValue>>),
+    Object(Vec<(Spanning<String>, Spanning<InputValue>)>),
+}
+
+impl InputValue {
+    pub fn null() -> InputValue {
+        InputValue::Null
+    }
+
+    pub fn int(i: i64) -> InputValue {
+        InputValue::Int(i)
+    }
+
+    pub fn float(f: f64) -> InputValue {
+        InputValue::Float(f)
+    }
+
+    pub fn string<T: AsRef<str>>(s: T) -> InputValue {
+        InputValue::String(s.as_ref().to_owned())
+    }
+
+    pub fn boolean(b: bool) -> InputValue {
+        InputValue::Boolean(b)
+    }
+
+    pub fn enum_value<T: AsRef<str>>(s: T) -> InputValue {
+        InputValue::Enum(s.as_ref().to_owned())
+    }
+
+    pub fn variable<T: AsRef<str>>(v: T) -> InputValue {
+        InputValue::Variable(v.as_ref().to_owned())
+    }
+
+    pub fn list(l: Vec<InputValue>) -> InputValue {
+        InputValue::List(l.into_iter().map(Spanning::unlocated).collect())
+    }
+
+    pub fn is_null(&self) -> bool {
+        matches!(*self, InputValue::Null)
+    }
+
+    /// Whether this value directly references a query variable.
+    pub fn is_variable(&self) -> bool {
+        matches!(*self, InputValue::Variable(_))
+    }
+}
+
+/// Human-readable rendering used in validation error messages.
+impl fmt::Display for InputValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            InputValue::Null => write!(f, "null"),
+            InputValue::Int(i) => write!(f, "{}", i),
+            InputValue::Float(x) => write!(f, "{}", x),
+            InputValue::String(ref s) => write!(f, "\"{}\"", s),
+            InputValue::Boolean(b) => write!(f, "{}", b),
+            InputValue::Enum(ref s) => write!(f, "{}", s),
+            InputValue::Variable(ref s) => write!(f, "${}", s),
+            InputValue::List(ref l) => {
+                (write!(f, "["))?;
+                for (i, v) in l.iter().enumerate() {
+                    if i > 0 {
+                        (write!(f, ", "))?;
+                    }
+                    (write!(f, "{}", v.item))?;
+                }
+                write!(f, "]")
+            }
+            InputValue::Object(ref o) => {
+                (write!(f, "{{"))?;
+                for (i, (k, v)) in o.iter().enumerate() {
+                    if i > 0 {
+                        (write!(f, ", "))?;
+                    }
+                    (write!(f, "{}: {}", k.item, v.item))?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+/// The list of arguments supplied to a field or directive.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Arguments {
+    pub items: Vec<(Spanning<String>, Spanning<InputValue>)>,
+}
+
+impl Arguments {
+    pub fn get(&self, key: &str) -> Option<&Spanning<InputValue>> {
+        self.items
+            .iter()
+            .find(|&(k, _)| k.item == key)
+            .map(|(_, v)| v)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Directive {
+    pub name: Spanning<String>,
+    pub arguments: Option<Spanning<Arguments>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Field {
+    pub alias: Option<Spanning<String>>,
+    pub name: Spanning<String>,
+    pub arguments: Option<Spanning<Arguments>>,
+    pub directives: Option<Vec<Spanning<Directive>>>,
+    pub selection_set: Option<Vec<Selection>>,
+}
+
+impl Field {
+    /// The response key a field result is keyed under — its alias, or its name.
+    pub fn response_key(&self) -> &str {
+        match self.alias {
+            Some(ref a) => &a.item,
+            None => &self.name.item,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FragmentSpread {
+    pub name: Spanning<String>,
+    pub directives: Option<Vec<Spanning<Directive>>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct InlineFragment {
+    pub type_condition: Option<Spanning<String>>,
+    pub directives: Option<Vec<Spanning<Directive>>>,
+    pub selection_set: Vec<Selection>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Selection {
+    Field(Spanning<Field>),
+    FragmentSpread(Spanning<FragmentSpread>),
+    InlineFragment(Spanning<InlineFragment>),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct VariableDefinition {
+    pub var_type: Spanning<Type>,
+    pub default_value: Option<Spanning<InputValue>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum OperationType {
+    Query,
+    Mutation,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Operation {
+    pub operation_type: OperationType,
+    pub name: Option<Spanning<String>>,
+    pub variable_definitions: Vec<(Spanning<String>, VariableDefinition)>,
+    pub selection_set: Vec<Selection>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Fragment {
+    pub name: Spanning<String>,
+    pub type_condition: Spanning<String>,
+    pub selection_set: Vec<Selection>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Definition {
+    Operation(Spanning<Operation>),
+    Fragment(Spanning<Fragment>),
+}
+
+pub type Document = Vec<Definition>;
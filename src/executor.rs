@@ -0,0 +1,284 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use ast::{Arguments, Definition, Directive, Fragment, InputValue, Operation, Selection};
+use introspection;
+use parser::{parse_document, ParseError, Spanning};
+use schema::model::{RootNode, SchemaType};
+use types::base::{FromInputValue, GraphQLType};
+use validation::{self, RuleError};
+use value::Value;
+
+/// The variables supplied to an operation, keyed by variable name.
+pub type Variables = HashMap<String, InputValue>;
+
+/// An error produced while resolving a field.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExecutionError {
+    pub message: String,
+}
+
+/// The top-level error type returned from [`execute`](fn.execute.html).
+#[derive(Debug, PartialEq)]
+pub enum GraphQLError {
+    ParseError(ParseError),
+    ValidationError(Vec<RuleError>),
+    NoOperationError(String),
+}
+
+/// Execution context threaded through field resolution.
+#[derive(Clone, Copy)]
+pub struct Executor<'a> {
+    schema: &'a SchemaType,
+    variables: &'a Variables,
+    fragments: &'a HashMap<String, Fragment>,
+    current_selection_set: Option<&'a [Selection]>,
+    errors: &'a RefCell<Vec<ExecutionError>>,
+}
+
+impl<'a> Executor<'a> {
+    pub fn schema(&self) -> &'a SchemaType {
+        self.schema
+    }
+
+    pub fn variables(&self) -> &'a Variables {
+        self.variables
+    }
+
+    pub fn current_selection_set(&self) -> Option<&'a [Selection]> {
+        self.current_selection_set
+    }
+
+    pub fn fragment(&self, name: &str) -> Option<&'a Fragment> {
+        self.fragments.get(name)
+    }
+
+    /// Derive a child executor positioned at a nested selection set.
+    pub fn sub(&self, selection_set: Option<&'a [Selection]>) -> Executor<'a> {
+        let mut child = *self;
+        child.current_selection_set = selection_set;
+        child
+    }
+
+    /// Substitute a variable reference with its coerced value.
+    pub fn resolve_input_value(&self, value: &InputValue) -> InputValue {
+        match *value {
+            InputValue::Variable(ref name) => {
+                self.variables.get(name).cloned().unwrap_or(InputValue::Null)
+            }
+            ref other => other.clone(),
+        }
+    }
+
+    /// Coerce the named argument into a Rust value.
+    pub fn get_argument<T: FromInputValue>(&self, arguments: &Arguments, name: &str) -> T {
+        let resolved = match arguments.get(name) {
+            Some(spanning) => self.resolve_input_value(&spanning.item),
+            None => InputValue::Null,
+        };
+        T::from_input_value(&resolved).expect("argument was validated before execution")
+    }
+
+    /// Record a field-resolution error without aborting the rest of the
+    /// query, mirroring GraphQL's per-field error semantics: the field that
+    /// raised it resolves to `null`, and the error is reported alongside the
+    /// response's data rather than aborting `execute`.
+    pub fn push_error(&self, message: String) {
+        self.errors.borrow_mut().push(ExecutionError { message });
+    }
+}
+
+/// Whether a selection carrying these directives should be included,
+/// applying the built-in `@skip`/`@include` semantics: `@skip(if: true)`
+/// wins over any `@include`, regardless of order.
+fn directives_allow(directives: &Option<Vec<Spanning<Directive>>>, executor: &Executor) -> bool {
+    let directives = match *directives {
+        Some(ref directives) => directives,
+        None => return true,
+    };
+
+    let mut included = true;
+
+    for directive in directives {
+        let condition = directive
+            .item
+            .arguments
+            .as_ref()
+            .and_then(|a| a.item.get("if"))
+            .map(|v| executor.resolve_input_value(&v.item));
+
+        let condition = matches!(condition, Some(InputValue::Boolean(true)));
+
+        match &directive.item.name.item[..] {
+            "skip" if condition => return false,
+            "include" if !condition => included = false,
+            "include" => included = true,
+            _ => {}
+        }
+    }
+
+    included
+}
+
+/// Resolve a selection set against a concrete object instance.
+pub fn resolve_selection_set<T: GraphQLType>(
+    instance: &T,
+    type_name: &str,
+    selections: &[Selection],
+    executor: &Executor,
+) -> Value {
+    let mut object: HashMap<String, Value> = HashMap::new();
+
+    for selection in selections {
+        match *selection {
+            Selection::Field(ref spanning) => {
+                let field = &spanning.item;
+                if !directives_allow(&field.directives, executor) {
+                    continue;
+                }
+                let name = &field.name.item;
+                let response_key = field.response_key().to_owned();
+
+                let empty = Arguments { items: Vec::new() };
+                let arguments = field.arguments.as_ref().map(|a| &a.item).unwrap_or(&empty);
+                let sub_selection = field.selection_set.as_ref().map(|s| &s[..]);
+
+                let value = if name == "__typename" {
+                    Value::string(instance.concrete_type_name())
+                } else if name == "__schema" {
+                    introspection::resolve_schema(executor.schema(), sub_selection)
+                } else if name == "__type" {
+                    let type_name = executor
+                        .resolve_input_value(&arguments.get("name").map(|s| s.item.clone())
+                            .unwrap_or(InputValue::Null));
+                    match type_name {
+                        InputValue::String(ref n) => {
+                            introspection::resolve_type_by_name(executor.schema(), n, sub_selection)
+                        }
+                        _ => Value::null(),
+                    }
+                } else {
+                    let child = executor.sub(sub_selection);
+                    instance.resolve_field(name, arguments, &child)
+                };
+
+                object.insert(response_key, value);
+            }
+            Selection::InlineFragment(ref spanning) => {
+                let fragment = &spanning.item;
+                if !directives_allow(&fragment.directives, executor) {
+                    continue;
+                }
+                let matches = match fragment.type_condition {
+                    Some(ref tc) => tc.item == type_name,
+                    None => true,
+                };
+                if matches {
+                    merge(&mut object,
+                          resolve_selection_set(instance, type_name, &fragment.selection_set, executor));
+                }
+            }
+            Selection::FragmentSpread(ref spanning) => {
+                if !directives_allow(&spanning.item.directives, executor) {
+                    continue;
+                }
+                if let Some(fragment) = executor.fragment(&spanning.item.name.item) {
+                    if fragment.type_condition.item == type_name {
+                        merge(&mut object,
+                              resolve_selection_set(instance, type_name, &fragment.selection_set, executor));
+                    }
+                }
+            }
+        }
+    }
+
+    Value::Object(object)
+}
+
+fn merge(target: &mut HashMap<String, Value>, value: Value) {
+    if let Value::Object(map) = value {
+        for (k, v) in map {
+            target.insert(k, v);
+        }
+    }
+}
+
+/// Parse, validate and execute a query against a schema.
+pub fn execute<QueryT, MutationT>(
+    document_source: &str,
+    operation_name: Option<&str>,
+    root_node: &RootNode<QueryT, MutationT>,
+    variables: &Variables,
+    _context: &(),
+) -> Result<(Value, Vec<ExecutionError>), GraphQLError>
+where
+    QueryT: GraphQLType,
+    MutationT: GraphQLType,
+{
+    let document = (parse_document(document_source).map_err(GraphQLError::ParseError))?;
+
+    let mut fragments = HashMap::new();
+    let mut operations = Vec::new();
+
+    for definition in &document {
+        match *definition {
+            Definition::Operation(ref op) => operations.push(&op.item),
+            Definition::Fragment(ref frag) => {
+                fragments.insert(frag.item.name.item.clone(), frag.item.clone());
+            }
+        }
+    }
+
+    let operation = (select_operation(&operations, operation_name))?;
+
+    let mut errors = Vec::new();
+    validation::validate_arguments(
+        &root_node.schema,
+        &root_node.schema.query_type_name,
+        &operation.selection_set,
+        &mut errors,
+    );
+
+    let coerced = validation::coerce_variables(&root_node.schema, operation, variables, &mut errors);
+
+    if !errors.is_empty() {
+        return Err(GraphQLError::ValidationError(errors));
+    }
+
+    let field_errors = RefCell::new(Vec::new());
+    let executor = Executor {
+        schema: &root_node.schema,
+        variables: &coerced,
+        fragments: &fragments,
+        current_selection_set: Some(&operation.selection_set),
+        errors: &field_errors,
+    };
+
+    let value = root_node
+        .query
+        .resolve(Some(&operation.selection_set), &executor);
+
+    Ok((value, field_errors.into_inner()))
+}
+
+fn select_operation<'a>(
+    operations: &[&'a Operation],
+    operation_name: Option<&str>,
+) -> Result<&'a Operation, GraphQLError> {
+    match operation_name {
+        Some(name) => operations
+            .iter()
+            .cloned()
+            .find(|op| op.name.as_ref().map(|n| &*n.item) == Some(name))
+            .ok_or_else(|| GraphQLError::NoOperationError(format!("Unknown operation \"{}\"", name))),
+        None => {
+            if operations.len() == 1 {
+                Ok(operations[0])
+            } else {
+                Err(GraphQLError::NoOperationError(
+                    "Must provide operation name when multiple operations are present".to_owned(),
+                ))
+            }
+        }
+    }
+}
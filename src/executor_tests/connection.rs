@@ -0,0 +1,161 @@
+use std::collections::HashMap;
+
+use value::Value;
+use executor::ExecutionError;
+use schema::model::RootNode;
+use types::base::InputValueError;
+use types::scalars::EmptyMutation;
+use relay::Connection;
+
+struct User { name: String }
+
+graphql_object!(User: () |&self| {
+    field name() -> &str { &self.name }
+});
+
+struct TestType;
+
+fn users() -> Vec<User> {
+    vec![
+        User { name: "Alice".to_owned() },
+        User { name: "Bob".to_owned() },
+        User { name: "Carol".to_owned() },
+    ]
+}
+
+graphql_object!(TestType: () |&self| {
+    field users(first: Option<i32>, after: Option<String>)
+        -> Result<Connection<User>, InputValueError>
+    {
+        Connection::from_slice(users(), first, after)
+    }
+});
+
+fn run_query<F>(query: &str, f: F)
+    where F: Fn(&HashMap<String, Value>)
+{
+    let schema = RootNode::new(TestType, EmptyMutation::<()>::new());
+
+    let (result, errs) = ::execute(query, None, &schema, &Default::default(), &())
+        .expect("Execution failed");
+
+    assert_eq!(errs, []);
+
+    let obj = result.as_object_value().expect("Result is not an object");
+
+    f(obj);
+}
+
+#[test]
+fn first_slices_edges_and_reports_next_page() {
+    run_query(
+        "{ users(first: 2) { edges { node { name } cursor } pageInfo { hasNextPage hasPreviousPage } } }",
+        |result| {
+            let conn = result
+                .get("users").expect("users field missing")
+                .as_object_value().expect("users is not an object");
+
+            let edges = conn
+                .get("edges").expect("edges field missing")
+                .as_list_value().expect("edges is not a list");
+
+            assert_eq!(edges.len(), 2);
+
+            let first_node = edges[0]
+                .as_object_value().unwrap()
+                .get("node").unwrap()
+                .as_object_value().unwrap();
+            assert_eq!(first_node.get("name"), Some(&Value::string("Alice")));
+
+            let page_info = conn
+                .get("pageInfo").unwrap()
+                .as_object_value().unwrap();
+            assert_eq!(page_info.get("hasNextPage"), Some(&Value::boolean(true)));
+            assert_eq!(page_info.get("hasPreviousPage"), Some(&Value::boolean(false)));
+        });
+}
+
+#[test]
+fn after_cursor_resumes_pagination() {
+    // Grab the cursor of the first edge, then page past it.
+    let schema = RootNode::new(TestType, EmptyMutation::<()>::new());
+    let (result, _) = ::execute(
+        "{ users(first: 1) { edges { cursor } } }",
+        None, &schema, &Default::default(), &())
+        .expect("Execution failed");
+
+    let cursor = result
+        .as_object_value().unwrap()
+        .get("users").unwrap().as_object_value().unwrap()
+        .get("edges").unwrap().as_list_value().unwrap()[0]
+        .as_object_value().unwrap()
+        .get("cursor").unwrap()
+        .as_string_value().unwrap()
+        .to_owned();
+
+    let query = format!(
+        r#"{{ users(first: 1, after: "{}") {{ edges {{ node {{ name }} }} }} }}"#,
+        cursor);
+
+    run_query(&query, |result| {
+        let node = result
+            .get("users").unwrap().as_object_value().unwrap()
+            .get("edges").unwrap().as_list_value().unwrap()[0]
+            .as_object_value().unwrap()
+            .get("node").unwrap().as_object_value().unwrap();
+        assert_eq!(node.get("name"), Some(&Value::string("Bob")));
+    });
+}
+
+fn unwrap_connection_err(result: Result<Connection<User>, InputValueError>) -> InputValueError {
+    match result {
+        Ok(_) => panic!("expected an InputValueError"),
+        Err(err) => err,
+    }
+}
+
+#[test]
+fn malformed_after_cursor_is_a_custom_input_error() {
+    let error = unwrap_connection_err(
+        Connection::from_slice(users(), None, Some("not a cursor".to_owned())),
+    );
+
+    assert_eq!(
+        error,
+        InputValueError::Custom(
+            "Invalid cursor for argument \"after\": \"not a cursor\"".to_owned(),
+        ),
+    );
+}
+
+#[test]
+fn negative_first_is_a_custom_input_error() {
+    let error = unwrap_connection_err(Connection::from_slice(users(), Some(-1), None));
+
+    assert_eq!(
+        error,
+        InputValueError::Custom(
+            "Argument \"first\" must be a non-negative integer, got -1".to_owned(),
+        ),
+    );
+}
+
+#[test]
+fn invalid_cursor_nulls_the_field_and_reports_an_error_instead_of_panicking() {
+    let schema = RootNode::new(TestType, EmptyMutation::<()>::new());
+
+    let (result, errs) = ::execute(
+        r#"{ users(after: "not-a-real-cursor") { edges { cursor } } }"#,
+        None, &schema, &Default::default(), &())
+        .expect("Execution failed");
+
+    assert_eq!(
+        result.as_object_value().unwrap().get("users"),
+        Some(&Value::null()));
+
+    assert_eq!(errs, [
+        ExecutionError {
+            message: "Invalid cursor for argument \"after\": \"not-a-real-cursor\"".to_owned(),
+        },
+    ]);
+}
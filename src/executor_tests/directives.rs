@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+
+use value::Value;
+use ast::InputValue;
+use executor::Variables;
+use schema::model::RootNode;
+use types::scalars::EmptyMutation;
+
+struct TestType;
+
+graphql_object!(TestType: () |&self| {
+    field a() -> &str { "a" }
+    field b() -> &str { "b" }
+});
+
+fn run_variable_query<F>(query: &str, vars: Variables, f: F)
+    where F: Fn(&HashMap<String, Value>)
+{
+    let schema = RootNode::new(TestType, EmptyMutation::<()>::new());
+
+    let (result, errs) = ::execute(query, None, &schema, &vars, &())
+        .expect("Execution failed");
+
+    assert_eq!(errs, []);
+
+    let obj = result.as_object_value().expect("Result is not an object");
+
+    f(obj);
+}
+
+fn run_query<F>(query: &str, f: F)
+    where F: Fn(&HashMap<String, Value>)
+{
+    run_variable_query(query, Variables::new(), f);
+}
+
+#[test]
+fn skip_omits_field() {
+    run_query(
+        "{ a b @skip(if: true) }",
+        |result| {
+            assert_eq!(result.get("a"), Some(&Value::string("a")));
+            assert_eq!(result.get("b"), None);
+        });
+}
+
+#[test]
+fn include_keeps_field() {
+    run_query(
+        "{ a b @include(if: true) }",
+        |result| {
+            assert_eq!(result.get("a"), Some(&Value::string("a")));
+            assert_eq!(result.get("b"), Some(&Value::string("b")));
+        });
+}
+
+#[test]
+fn include_false_omits_field() {
+    run_query(
+        "{ a b @include(if: false) }",
+        |result| {
+            assert_eq!(result.get("b"), None);
+        });
+}
+
+#[test]
+fn skip_wins_over_include() {
+    run_query(
+        "{ b @skip(if: true) @include(if: true) }",
+        |result| {
+            assert_eq!(result.get("b"), None);
+        });
+}
+
+#[test]
+fn directive_condition_from_variable() {
+    run_variable_query(
+        "query q($cond: Boolean!) { b @skip(if: $cond) }",
+        vec![
+            ("cond".to_owned(), InputValue::boolean(true)),
+        ].into_iter().collect(),
+        |result| {
+            assert_eq!(result.get("b"), None);
+        });
+}
+
+#[test]
+fn skip_omits_inline_fragment() {
+    run_query(
+        "{ a ... on TestType @skip(if: true) { b } }",
+        |result| {
+            assert_eq!(result.get("a"), Some(&Value::string("a")));
+            assert_eq!(result.get("b"), None);
+        });
+}
+
+#[test]
+fn include_false_omits_fragment_spread() {
+    run_query(
+        "{ a ...Frag @include(if: false) } fragment Frag on TestType { b }",
+        |result| {
+            assert_eq!(result.get("a"), Some(&Value::string("a")));
+            assert_eq!(result.get("b"), None);
+        });
+}
+
+#[test]
+fn registers_builtin_directives() {
+    run_query(
+        "{ __schema { directives { name locations } } }",
+        |result| {
+            let names = result
+                .get("__schema").expect("__schema field missing")
+                .as_object_value().expect("__schema is not an object")
+                .get("directives").expect("directives field missing")
+                .as_list_value().expect("directives is not a list")
+                .iter()
+                .filter_map(|v| v.as_object_value())
+                .filter_map(|o| o.get("name"))
+                .filter_map(|v| v.as_string_value())
+                .collect::<Vec<_>>();
+
+            assert!(names.contains(&"skip"));
+            assert!(names.contains(&"include"));
+        });
+}
@@ -7,6 +7,7 @@ use schema::model::RootNode;
 use ::GraphQLError::ValidationError;
 use validation::RuleError;
 use parser::SourcePosition;
+use types::base::InputValueError;
 use types::scalars::EmptyMutation;
 
 #[derive(Debug)]
@@ -14,9 +15,9 @@ enum Color { Red, Green, Blue }
 struct TestType;
 
 graphql_enum!(Color {
-    Color::Red => "RED",
+    Color::Red => "RED" as "The primary red",
     Color::Green => "GREEN",
-    Color::Blue => "BLUE",
+    Color::Blue => "BLUE" as "The primary blue" deprecated "use CRIMSON",
 });
 
 graphql_object!(TestType: () |&self| {
@@ -27,10 +28,14 @@ graphql_object!(TestType: () |&self| {
     field a_color() -> Color {
         Color::Red
     }
+
+    field maybe_color(color: Option<Color>) -> Option<Color> {
+        color
+    }
 });
 
 fn run_variable_query<F>(query: &str, vars: Variables, f: F)
-    where F: Fn(&HashMap<String, Value>) -> ()
+    where F: Fn(&HashMap<String, Value>)
 {
     let schema = RootNode::new(TestType, EmptyMutation::<()>::new());
 
@@ -47,7 +52,7 @@ fn run_variable_query<F>(query: &str, vars: Variables, f: F)
 }
 
 fn run_query<F>(query: &str, f: F)
-    where F: Fn(&HashMap<String, Value>) -> ()
+    where F: Fn(&HashMap<String, Value>)
 {
     run_variable_query(query, Variables::new(), f);
 }
@@ -74,6 +79,77 @@ fn serializes_as_output() {
         });
 }
 
+#[test]
+fn exposes_descriptions_in_introspection() {
+    run_query(
+        r#"{ __type(name: "Color") { enumValues { name description } } }"#,
+        |result| {
+            let type_info = result
+                .get("__type").expect("__type field missing")
+                .as_object_value().expect("__type is not an object");
+
+            let values = type_info
+                .get("enumValues").expect("enumValues field missing")
+                .as_list_value().expect("enumValues is not a list");
+
+            assert!(values.contains(&Value::object(vec![
+                ("name", Value::string("RED")),
+                ("description", Value::string("The primary red")),
+            ].into_iter().collect())));
+
+            assert!(values.contains(&Value::object(vec![
+                ("name", Value::string("GREEN")),
+                ("description", Value::null()),
+            ].into_iter().collect())));
+        });
+}
+
+#[test]
+fn hides_deprecated_values_by_default() {
+    run_query(
+        r#"{ __type(name: "Color") { enumValues { name } } }"#,
+        |result| {
+            let values = result
+                .get("__type").expect("__type field missing")
+                .as_object_value().expect("__type is not an object")
+                .get("enumValues").expect("enumValues field missing")
+                .as_list_value().expect("enumValues is not a list");
+
+            let names = values.iter()
+                .filter_map(|v| v.as_object_value())
+                .filter_map(|o| o.get("name"))
+                .filter_map(|v| v.as_string_value())
+                .collect::<Vec<_>>();
+
+            assert_eq!(names, vec!["RED", "GREEN"]);
+        });
+}
+
+#[test]
+fn includes_deprecated_values_on_request() {
+    run_query(
+        r#"{ __type(name: "Color") { enumValues(includeDeprecated: true) { name isDeprecated deprecationReason } } }"#,
+        |result| {
+            let values = result
+                .get("__type").expect("__type field missing")
+                .as_object_value().expect("__type is not an object")
+                .get("enumValues").expect("enumValues field missing")
+                .as_list_value().expect("enumValues is not a list");
+
+            assert!(values.contains(&Value::object(vec![
+                ("name", Value::string("BLUE")),
+                ("isDeprecated", Value::boolean(true)),
+                ("deprecationReason", Value::string("use CRIMSON")),
+            ].into_iter().collect())));
+
+            assert!(values.contains(&Value::object(vec![
+                ("name", Value::string("RED")),
+                ("isDeprecated", Value::boolean(false)),
+                ("deprecationReason", Value::null()),
+            ].into_iter().collect())));
+        });
+}
+
 #[test]
 fn does_not_accept_string_literals() {
     let schema = RootNode::new(TestType, EmptyMutation::<()>::new());
@@ -107,6 +183,44 @@ fn accepts_strings_in_variables() {
         });
 }
 
+#[test]
+fn uses_default_when_variable_omitted() {
+    run_variable_query(
+        "query q($color: Color = GREEN) { toString(color: $color) }",
+        Variables::new(),
+        |result| {
+            assert_eq!(
+                result.get("toString"),
+                Some(&Value::string("Color::Green")));
+        });
+}
+
+#[test]
+fn supplied_value_overrides_default() {
+    run_variable_query(
+        "query q($color: Color = GREEN) { toString(color: $color) }",
+        vec![
+            ("color".to_owned(), InputValue::string("BLUE")),
+        ].into_iter().collect(),
+        |result| {
+            assert_eq!(
+                result.get("toString"),
+                Some(&Value::string("Color::Blue")));
+        });
+}
+
+#[test]
+fn nullable_variable_without_default_coerces_to_null() {
+    run_variable_query(
+        "query q($color: Color) { maybeColor(color: $color) }",
+        Variables::new(),
+        |result| {
+            assert_eq!(
+                result.get("maybeColor"),
+                Some(&Value::null()));
+        });
+}
+
 #[test]
 fn does_not_accept_incorrect_enum_name_in_variables() {
     let schema = RootNode::new(TestType, EmptyMutation::<()>::new());
@@ -119,12 +233,20 @@ fn does_not_accept_incorrect_enum_name_in_variables() {
     let error = ::execute(query, None, &schema, &vars, &())
         .unwrap_err();
 
-    assert_eq!(error, ValidationError(vec![
-        RuleError::new(
-            r#"Variable "$color" got invalid value. Invalid value for enum "Color"."#,
-            &[SourcePosition::new(8, 0, 8)],
-        ),
-    ]));
+    let errs = match error {
+        ValidationError(errs) => errs,
+        other => panic!("expected a validation error, got {:?}", other),
+    };
+
+    assert_eq!(errs.len(), 1);
+    assert_eq!(errs[0].locations(), &[SourcePosition::new(8, 0, 8)]);
+    assert_eq!(
+        errs[0].kind(),
+        Some(&InputValueError::UnknownEnumValue {
+            got: InputValue::string("BLURPLE"),
+            expected_one_of: vec!["RED".to_owned(), "GREEN".to_owned(), "BLUE".to_owned()],
+        }),
+    );
 }
 
 #[test]
@@ -139,10 +261,15 @@ fn does_not_accept_incorrect_type_in_variables() {
     let error = ::execute(query, None, &schema, &vars, &())
         .unwrap_err();
 
-    assert_eq!(error, ValidationError(vec![
-        RuleError::new(
-            r#"Variable "$color" got invalid value. Expected "Color", found not a string or enum."#,
-            &[SourcePosition::new(8, 0, 8)],
-        ),
-    ]));
+    let errs = match error {
+        ValidationError(errs) => errs,
+        other => panic!("expected a validation error, got {:?}", other),
+    };
+
+    assert_eq!(errs.len(), 1);
+    assert_eq!(errs[0].locations(), &[SourcePosition::new(8, 0, 8)]);
+    assert_eq!(
+        errs[0].kind(),
+        Some(&InputValueError::ExpectedType(InputValue::int(123))),
+    );
 }
@@ -0,0 +1,4 @@
+mod connection;
+mod directives;
+mod enums;
+mod unions;
@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+
+use value::Value;
+use schema::model::RootNode;
+use types::scalars::EmptyMutation;
+
+struct Cat { name: String }
+struct Dog { name: String }
+
+#[allow(dead_code)]
+enum Pet {
+    Cat(Cat),
+    Dog(Dog),
+}
+
+struct TestType;
+
+graphql_object!(Cat: () |&self| {
+    field name() -> &str { &self.name }
+    field meow() -> bool { true }
+});
+
+graphql_object!(Dog: () |&self| {
+    field name() -> &str { &self.name }
+    field bark() -> bool { true }
+});
+
+graphql_union!(Pet: () |&self| {
+    instance_resolvers: |&_| {
+        Cat => match *self { Pet::Cat(ref c) => Some(c), _ => None },
+        Dog => match *self { Pet::Dog(ref d) => Some(d), _ => None },
+    }
+});
+
+graphql_object!(TestType: () |&self| {
+    field a_pet() -> Pet {
+        Pet::Dog(Dog { name: "Rex".to_owned() })
+    }
+});
+
+fn run_query<F>(query: &str, f: F)
+    where F: Fn(&HashMap<String, Value>)
+{
+    let schema = RootNode::new(TestType, EmptyMutation::<()>::new());
+
+    let (result, errs) = ::execute(query, None, &schema, &Default::default(), &())
+        .expect("Execution failed");
+
+    assert_eq!(errs, []);
+
+    let obj = result.as_object_value().expect("Result is not an object");
+
+    f(obj);
+}
+
+#[test]
+fn resolves_concrete_variant() {
+    run_query(
+        "{ aPet { __typename ... on Dog { name bark } } }",
+        |result| {
+            let pet = result
+                .get("aPet").expect("aPet field missing")
+                .as_object_value().expect("aPet is not an object");
+
+            assert_eq!(pet.get("__typename"), Some(&Value::string("Dog")));
+            assert_eq!(pet.get("name"), Some(&Value::string("Rex")));
+            assert_eq!(pet.get("bark"), Some(&Value::boolean(true)));
+        });
+}
+
+#[test]
+fn reports_possible_types() {
+    run_query(
+        r#"{ __type(name: "Pet") { possibleTypes { name } } }"#,
+        |result| {
+            let names = result
+                .get("__type").expect("__type field missing")
+                .as_object_value().expect("__type is not an object")
+                .get("possibleTypes").expect("possibleTypes field missing")
+                .as_list_value().expect("possibleTypes is not a list")
+                .iter()
+                .filter_map(|v| v.as_object_value())
+                .filter_map(|o| o.get("name"))
+                .filter_map(|v| v.as_string_value())
+                .collect::<Vec<_>>();
+
+            assert!(names.contains(&"Cat"));
+            assert!(names.contains(&"Dog"));
+        });
+}
@@ -0,0 +1,177 @@
+//! Resolvers for the built-in introspection fields `__schema`, `__type` and
+//! `__typename`.
+
+use ast::{Field, InputValue, Selection};
+use schema::model::{DirectiveType, EnumValue, MetaType, SchemaType};
+use value::Value;
+
+fn selected_fields(selection: Option<&[Selection]>) -> Vec<&Field> {
+    let mut fields = Vec::new();
+    if let Some(selections) = selection {
+        for sel in selections {
+            if let Selection::Field(ref spanning) = *sel {
+                fields.push(&spanning.item);
+            }
+        }
+    }
+    fields
+}
+
+fn opt_string(value: Option<&str>) -> Value {
+    match value {
+        Some(s) => Value::string(s),
+        None => Value::null(),
+    }
+}
+
+fn arg_bool(field: &Field, name: &str) -> bool {
+    field
+        .arguments
+        .as_ref()
+        .and_then(|a| a.item.get(name))
+        .map(|v| match v.item {
+            InputValue::Boolean(b) => b,
+            _ => false,
+        })
+        .unwrap_or(false)
+}
+
+/// Resolve a `__schema { ... }` selection.
+pub fn resolve_schema(schema: &SchemaType, selection: Option<&[Selection]>) -> Value {
+    let mut object = ::std::collections::HashMap::new();
+
+    for field in selected_fields(selection) {
+        let key = field.response_key().to_owned();
+        let sub = field.selection_set.as_ref().map(|s| &s[..]);
+        let value = match &field.name.item[..] {
+            "directives" => Value::list(
+                schema
+                    .directives
+                    .iter()
+                    .map(|d| resolve_directive(d, sub))
+                    .collect(),
+            ),
+            "types" => Value::list(
+                schema
+                    .types
+                    .values()
+                    .filter_map(|t| t.name().map(|_| resolve_type(schema, t, sub)))
+                    .collect(),
+            ),
+            "queryType" => resolve_type_by_name(schema, &schema.query_type_name, sub),
+            "mutationType" => resolve_type_by_name(schema, &schema.mutation_type_name, sub),
+            _ => Value::null(),
+        };
+        object.insert(key, value);
+    }
+
+    Value::Object(object)
+}
+
+/// Resolve a `__type(name: ...) { ... }` selection.
+pub fn resolve_type_by_name(schema: &SchemaType, name: &str, selection: Option<&[Selection]>) -> Value {
+    match schema.type_by_name(name) {
+        Some(meta) => resolve_type(schema, meta, selection),
+        None => Value::null(),
+    }
+}
+
+fn resolve_type(schema: &SchemaType, meta: &MetaType, selection: Option<&[Selection]>) -> Value {
+    let mut object = ::std::collections::HashMap::new();
+
+    for field in selected_fields(selection) {
+        let key = field.response_key().to_owned();
+        let sub = field.selection_set.as_ref().map(|s| &s[..]);
+        let value = match &field.name.item[..] {
+            "name" => opt_string(meta.name()),
+            "description" => opt_string(meta.description()),
+            "kind" => Value::string(meta.type_kind()),
+            "enumValues" => resolve_enum_values(meta, field, sub),
+            "possibleTypes" => resolve_possible_types(schema, meta, sub),
+            _ => Value::null(),
+        };
+        object.insert(key, value);
+    }
+
+    Value::Object(object)
+}
+
+fn resolve_enum_values(meta: &MetaType, field: &Field, selection: Option<&[Selection]>) -> Value {
+    let values = match *meta {
+        MetaType::Enum { ref values, .. } => values,
+        _ => return Value::null(),
+    };
+
+    let include_deprecated = arg_bool(field, "includeDeprecated");
+
+    Value::list(
+        values
+            .iter()
+            .filter(|v| include_deprecated || v.deprecation_reason.is_none())
+            .map(|v| resolve_enum_value(v, selection))
+            .collect(),
+    )
+}
+
+fn resolve_enum_value(value: &EnumValue, selection: Option<&[Selection]>) -> Value {
+    let mut object = ::std::collections::HashMap::new();
+
+    for field in selected_fields(selection) {
+        let key = field.response_key().to_owned();
+        let resolved = match &field.name.item[..] {
+            "name" => Value::string(&value.name),
+            "description" => opt_string(value.description.as_ref().map(|s| &s[..])),
+            "isDeprecated" => Value::boolean(value.deprecation_reason.is_some()),
+            "deprecationReason" => opt_string(value.deprecation_reason.as_ref().map(|s| &s[..])),
+            _ => Value::null(),
+        };
+        object.insert(key, resolved);
+    }
+
+    Value::Object(object)
+}
+
+fn resolve_possible_types(schema: &SchemaType, meta: &MetaType, selection: Option<&[Selection]>) -> Value {
+    match *meta {
+        MetaType::Union { ref possible_types, .. } => Value::list(
+            possible_types
+                .iter()
+                .map(|name| resolve_type_by_name(schema, name, selection))
+                .collect(),
+        ),
+        _ => Value::null(),
+    }
+}
+
+fn resolve_directive(directive: &DirectiveType, selection: Option<&[Selection]>) -> Value {
+    let mut object = ::std::collections::HashMap::new();
+
+    for field in selected_fields(selection) {
+        let key = field.response_key().to_owned();
+        let value = match &field.name.item[..] {
+            "name" => Value::string(&directive.name),
+            "locations" => Value::list(
+                directive
+                    .locations
+                    .iter()
+                    .map(Value::string)
+                    .collect(),
+            ),
+            "args" => Value::list(
+                directive
+                    .arguments
+                    .iter()
+                    .map(|a| {
+                        let mut arg = ::std::collections::HashMap::new();
+                        arg.insert("name".to_owned(), Value::string(&a.name));
+                        Value::Object(arg)
+                    })
+                    .collect(),
+            ),
+            _ => Value::null(),
+        };
+        object.insert(key, value);
+    }
+
+    Value::Object(object)
+}
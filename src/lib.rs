@@ -0,0 +1,21 @@
+//! A small GraphQL server library: define a schema from Rust types with the
+//! `graphql_object!` / `graphql_enum!` macros and execute queries against it.
+
+#[macro_use]
+pub mod macros;
+
+pub mod ast;
+pub mod executor;
+pub mod introspection;
+pub mod parser;
+pub mod relay;
+pub mod schema;
+pub mod types;
+pub mod util;
+pub mod validation;
+pub mod value;
+
+pub use executor::{execute, GraphQLError};
+
+#[cfg(test)]
+mod executor_tests;
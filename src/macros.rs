@@ -0,0 +1,218 @@
+//! The `graphql_enum!`, `graphql_object!` and `graphql_union!`
+//! schema-definition macros.
+
+/// Define a GraphQL enum type from a Rust enum.
+///
+/// Each variant maps to a GraphQL value name and may optionally carry a
+/// description (`as "..."`) and a deprecation marker (`deprecated "..."`).
+#[macro_export]
+macro_rules! graphql_enum {
+    (
+        $name:ty {
+            $(
+                $variant:path => $gql_name:literal
+                    $(as $description:literal)?
+                    $(deprecated $deprecation:literal)?
+            ),* $(,)?
+        }
+    ) => {
+        impl $crate::types::base::GraphQLType for $name {
+            fn name() -> ::std::option::Option<String> {
+                Some(stringify!($name).to_owned())
+            }
+
+            fn meta(_registry: &mut $crate::schema::model::Registry)
+                -> $crate::schema::model::MetaType
+            {
+                $crate::schema::model::MetaType::Enum {
+                    name: stringify!($name).to_owned(),
+                    description: None,
+                    values: vec![
+                        $(
+                            $crate::schema::model::EnumValue {
+                                name: $gql_name.to_owned(),
+                                description: ::std::option::Option::<String>::None
+                                    $( .or(Some($description.to_owned())) )?,
+                                deprecation_reason: ::std::option::Option::<String>::None
+                                    $( .or(Some($deprecation.to_owned())) )?,
+                            }
+                        ),*
+                    ],
+                }
+            }
+
+            fn resolve(
+                &self,
+                _selection: ::std::option::Option<&[$crate::ast::Selection]>,
+                _executor: &$crate::executor::Executor,
+            ) -> $crate::value::Value {
+                $crate::value::Value::string(match *self {
+                    $( $variant => $gql_name ),*
+                })
+            }
+        }
+
+        impl $crate::types::base::FromInputValue for $name {
+            fn from_input_value(value: &$crate::ast::InputValue)
+                -> ::std::result::Result<$name, $crate::types::base::InputValueError>
+            {
+                match *value {
+                    $crate::ast::InputValue::Enum(ref s)
+                    | $crate::ast::InputValue::String(ref s) => match &s[..] {
+                        $( $gql_name => Ok($variant), )*
+                        _ => Err($crate::types::base::InputValueError::UnknownEnumValue {
+                            got: value.clone(),
+                            expected_one_of: vec![ $( $gql_name.to_owned() ),* ],
+                        }),
+                    },
+                    ref other => Err($crate::types::base::InputValueError::ExpectedType(other.clone())),
+                }
+            }
+        }
+    };
+}
+
+/// Define a GraphQL object type with resolvable fields.
+#[macro_export]
+macro_rules! graphql_object {
+    (
+        $name:ty : $ctx:ty | & $self_var:ident | {
+            $(
+                field $field_name:ident ( $( $arg_name:ident : $arg_type:ty ),* $(,)? )
+                    -> $return_type:ty $body:block
+            )*
+        }
+    ) => {
+        impl $crate::types::base::GraphQLType for $name {
+            fn name() -> ::std::option::Option<String> {
+                Some(stringify!($name).to_owned())
+            }
+
+            fn meta(registry: &mut $crate::schema::model::Registry)
+                -> $crate::schema::model::MetaType
+            {
+                let _ = &registry;
+                let fields = vec![
+                    $(
+                        $crate::schema::model::Field {
+                            name: $crate::util::to_camel_case(stringify!($field_name)),
+                            field_type:
+                                <$return_type as $crate::types::base::GraphQLType>::type_ref(registry),
+                            arguments: vec![
+                                $(
+                                    $crate::schema::model::Argument {
+                                        name: $crate::util::to_camel_case(stringify!($arg_name)),
+                                        arg_type:
+                                            <$arg_type as $crate::types::base::GraphQLType>::type_ref(registry),
+                                        description: None,
+                                    }
+                                ),*
+                            ],
+                            description: None,
+                            deprecation_reason: None,
+                        }
+                    ),*
+                ];
+
+                $crate::schema::model::MetaType::Object {
+                    name: stringify!($name).to_owned(),
+                    description: None,
+                    fields,
+                }
+            }
+
+            fn resolve(
+                &$self_var,
+                selection: ::std::option::Option<&[$crate::ast::Selection]>,
+                executor: &$crate::executor::Executor,
+            ) -> $crate::value::Value {
+                match selection {
+                    Some(sel) => $crate::executor::resolve_selection_set(
+                        $self_var, stringify!($name), sel, executor),
+                    None => $crate::value::Value::null(),
+                }
+            }
+
+            #[allow(unused_variables)]
+            fn resolve_field(
+                &$self_var,
+                field: &str,
+                arguments: &$crate::ast::Arguments,
+                executor: &$crate::executor::Executor,
+            ) -> $crate::value::Value {
+                $(
+                    if field == $crate::util::to_camel_case(stringify!($field_name)).as_str() {
+                        $(
+                            let $arg_name: $arg_type = executor.get_argument(
+                                arguments,
+                                $crate::util::to_camel_case(stringify!($arg_name)).as_str());
+                        )*
+                        let result: $return_type = $body;
+                        return $crate::types::base::resolve_value(result, executor);
+                    }
+                )*
+                $crate::value::Value::null()
+            }
+        }
+    };
+}
+
+/// Define a GraphQL union type over a Rust enum whose variants each wrap a
+/// concrete `graphql_object!` type.
+///
+/// `instance_resolvers` matches on the enum to pick out the wrapped value for
+/// each possible member type; resolution and `__typename` dispatch delegate
+/// to whichever variant's object resolver matches.
+#[macro_export]
+macro_rules! graphql_union {
+    (
+        $name:ty : $ctx:ty | & $self_var:ident | {
+            instance_resolvers: | & $ctx_var:pat_param | {
+                $( $variant_ty:ty => $resolver:expr ),* $(,)?
+            }
+        }
+    ) => {
+        impl $crate::types::base::GraphQLType for $name {
+            fn name() -> ::std::option::Option<String> {
+                Some(stringify!($name).to_owned())
+            }
+
+            fn meta(registry: &mut $crate::schema::model::Registry)
+                -> $crate::schema::model::MetaType
+            {
+                $(
+                    <$variant_ty as $crate::types::base::GraphQLType>::type_ref(registry);
+                )*
+                $crate::schema::model::MetaType::Union {
+                    name: stringify!($name).to_owned(),
+                    description: None,
+                    possible_types: vec![ $( stringify!($variant_ty).to_owned() ),* ],
+                }
+            }
+
+            fn resolve(
+                &$self_var,
+                selection: ::std::option::Option<&[$crate::ast::Selection]>,
+                executor: &$crate::executor::Executor,
+            ) -> $crate::value::Value {
+                let $ctx_var = ();
+                $(
+                    if let Some(value) = $resolver {
+                        return $crate::types::base::GraphQLType::resolve(value, selection, executor);
+                    }
+                )*
+                $crate::value::Value::null()
+            }
+
+            fn concrete_type_name(&$self_var) -> String {
+                let $ctx_var = ();
+                $(
+                    if let Some(_) = $resolver {
+                        return stringify!($variant_ty).to_owned();
+                    }
+                )*
+                String::new()
+            }
+        }
+    };
+}
@@ -0,0 +1,606 @@
+//! A minimal hand-written lexer and recursive-descent parser for GraphQL
+//! query documents.
+
+use std::fmt;
+
+use ast::{Arguments, Definition, Directive, Document, Field, Fragment, FragmentSpread,
+          InlineFragment, InputValue, Operation, OperationType, Selection, Type,
+          VariableDefinition};
+
+/// A zero-based position in the source query.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SourcePosition {
+    index: usize,
+    line: usize,
+    col: usize,
+}
+
+impl SourcePosition {
+    pub fn new(index: usize, line: usize, col: usize) -> SourcePosition {
+        SourcePosition {
+            index,
+            line,
+            col,
+        }
+    }
+
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn col(&self) -> usize {
+        self.col
+    }
+}
+
+/// A parsed item together with the source range it spans.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Spanning<T> {
+    pub item: T,
+    pub start: SourcePosition,
+    pub end: SourcePosition,
+}
+
+impl<T> Spanning<T> {
+    pub fn new(start: SourcePosition, end: SourcePosition, item: T) -> Spanning<T> {
+        Spanning {
+            item,
+            start,
+            end,
+        }
+    }
+
+    pub fn start_ph(start: SourcePosition, item: T) -> Spanning<T> {
+        Spanning {
+            item,
+            start,
+            end: start,
+        }
+    }
+
+    /// A span for a value with no meaningful source location.
+    pub fn unlocated(item: T) -> Spanning<T> {
+        let z = SourcePosition::new(0, 0, 0);
+        Spanning {
+            item,
+            start: z,
+            end: z,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ParseError(pub String, pub SourcePosition);
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Name(String),
+    Int(i64),
+    Float(f64),
+    Str(String),
+    Punct(char),
+    Spread,
+}
+
+struct Lexer {
+    tokens: Vec<Spanning<Token>>,
+    pos: usize,
+    eof: SourcePosition,
+}
+
+fn lex(source: &str) -> Result<Lexer, ParseError> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut i = 0;
+    let mut line = 0;
+    let mut col = 0;
+    let mut tokens = Vec::new();
+
+    macro_rules! advance {
+        () => {{
+            if chars[i] == '\n' {
+                line += 1;
+                col = 0;
+            } else {
+                col += 1;
+            }
+            i += 1;
+        }};
+    }
+
+    while i < chars.len() {
+        let c = chars[i];
+        let start = SourcePosition::new(i, line, col);
+
+        if c.is_whitespace() || c == ',' {
+            advance!();
+            continue;
+        }
+
+        if c == '#' {
+            while i < chars.len() && chars[i] != '\n' {
+                advance!();
+            }
+            continue;
+        }
+
+        if c == '.' {
+            if i + 2 < chars.len() && chars[i + 1] == '.' && chars[i + 2] == '.' {
+                advance!();
+                advance!();
+                advance!();
+                tokens.push(Spanning::new(start, SourcePosition::new(i, line, col), Token::Spread));
+                continue;
+            }
+            return Err(ParseError("Unexpected character \".\"".to_owned(), start));
+        }
+
+        if "{}()[]:!$@=".contains(c) {
+            advance!();
+            tokens.push(Spanning::new(start, SourcePosition::new(i, line, col), Token::Punct(c)));
+            continue;
+        }
+
+        if c == '"' {
+            advance!();
+            let mut s = String::new();
+            while i < chars.len() && chars[i] != '"' {
+                if chars[i] == '\\' {
+                    advance!();
+                    if i >= chars.len() {
+                        break;
+                    }
+                    let e = chars[i];
+                    s.push(match e {
+                        'n' => '\n',
+                        't' => '\t',
+                        'r' => '\r',
+                        other => other,
+                    });
+                    advance!();
+                } else {
+                    s.push(chars[i]);
+                    advance!();
+                }
+            }
+            if i >= chars.len() {
+                return Err(ParseError("Unterminated string".to_owned(), start));
+            }
+            advance!(); // closing quote
+            tokens.push(Spanning::new(start, SourcePosition::new(i, line, col), Token::Str(s)));
+            continue;
+        }
+
+        if c == '-' || c.is_ascii_digit() {
+            let mut num = String::new();
+            let mut is_float = false;
+            if c == '-' {
+                num.push(c);
+                advance!();
+            }
+            while i < chars.len() && chars[i].is_ascii_digit() {
+                num.push(chars[i]);
+                advance!();
+            }
+            if i < chars.len() && chars[i] == '.' {
+                is_float = true;
+                num.push('.');
+                advance!();
+                while i < chars.len() && chars[i].is_ascii_digit() {
+                    num.push(chars[i]);
+                    advance!();
+                }
+            }
+            let end = SourcePosition::new(i, line, col);
+            if is_float {
+                let f = (num.parse::<f64>()
+                    .map_err(|_| ParseError("Invalid number".to_owned(), start)))?;
+                tokens.push(Spanning::new(start, end, Token::Float(f)));
+            } else {
+                let n = (num.parse::<i64>()
+                    .map_err(|_| ParseError("Invalid number".to_owned(), start)))?;
+                tokens.push(Spanning::new(start, end, Token::Int(n)));
+            }
+            continue;
+        }
+
+        if c.is_alphabetic() || c == '_' {
+            let mut name = String::new();
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                name.push(chars[i]);
+                advance!();
+            }
+            tokens.push(Spanning::new(start, SourcePosition::new(i, line, col), Token::Name(name)));
+            continue;
+        }
+
+        return Err(ParseError(format!("Unexpected character \"{}\"", c), start));
+    }
+
+    Ok(Lexer {
+        tokens,
+        pos: 0,
+        eof: SourcePosition::new(i, line, col),
+    })
+}
+
+impl Lexer {
+    fn peek(&self) -> Option<&Spanning<Token>> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next_pos(&self) -> SourcePosition {
+        match self.peek() {
+            Some(t) => t.start,
+            None => self.eof,
+        }
+    }
+
+    fn bump(&mut self) -> Result<Spanning<Token>, ParseError> {
+        match self.tokens.get(self.pos).cloned() {
+            Some(t) => {
+                self.pos += 1;
+                Ok(t)
+            }
+            None => Err(ParseError("Unexpected end of input".to_owned(), self.eof)),
+        }
+    }
+
+    fn is_punct(&self, c: char) -> bool {
+        match self.peek() {
+            Some(&Spanning { item: Token::Punct(p), .. }) => p == c,
+            _ => false,
+        }
+    }
+
+    fn is_name(&self, name: &str) -> bool {
+        match self.peek() {
+            Some(&Spanning { item: Token::Name(ref n), .. }) => n == name,
+            _ => false,
+        }
+    }
+
+    fn expect_punct(&mut self, c: char) -> Result<(), ParseError> {
+        let t = (self.bump())?;
+        match t.item {
+            Token::Punct(p) if p == c => Ok(()),
+            other => Err(ParseError(format!("Expected \"{}\", found {:?}", c, other), t.start)),
+        }
+    }
+
+    fn expect_name(&mut self) -> Result<Spanning<String>, ParseError> {
+        let t = (self.bump())?;
+        match t.item {
+            Token::Name(n) => Ok(Spanning::new(t.start, t.end, n)),
+            other => Err(ParseError(format!("Expected a name, found {:?}", other), t.start)),
+        }
+    }
+}
+
+/// Parse a GraphQL query document.
+pub fn parse_document(source: &str) -> Result<Document, ParseError> {
+    let mut lexer = (lex(source))?;
+    let mut defs = Vec::new();
+
+    while lexer.peek().is_some() {
+        defs.push((parse_definition(&mut lexer))?);
+    }
+
+    if defs.is_empty() {
+        return Err(ParseError("Expected a query document".to_owned(), lexer.eof));
+    }
+
+    Ok(defs)
+}
+
+fn parse_definition(lexer: &mut Lexer) -> Result<Definition, ParseError> {
+    if lexer.is_punct('{') {
+        let start = lexer.next_pos();
+        let selection_set = (parse_selection_set(lexer))?;
+        let end = lexer.next_pos();
+        return Ok(Definition::Operation(Spanning::new(start, end, Operation {
+            operation_type: OperationType::Query,
+            name: None,
+            variable_definitions: Vec::new(),
+            selection_set,
+        })));
+    }
+
+    if lexer.is_name("fragment") {
+        return parse_fragment(lexer).map(Definition::Fragment);
+    }
+
+    parse_operation(lexer).map(Definition::Operation)
+}
+
+fn parse_operation(lexer: &mut Lexer) -> Result<Spanning<Operation>, ParseError> {
+    let start = lexer.next_pos();
+    let kw = (lexer.expect_name())?;
+    let operation_type = match &*kw.item {
+        "query" => OperationType::Query,
+        "mutation" => OperationType::Mutation,
+        other => {
+            return Err(ParseError(format!("Unexpected operation \"{}\"", other), kw.start));
+        }
+    };
+
+    let name = if let Some(&Spanning { item: Token::Name(_), .. }) = lexer.peek() {
+        Some((lexer.expect_name())?)
+    } else {
+        None
+    };
+
+    let variable_definitions = if lexer.is_punct('(') {
+        (parse_variable_definitions(lexer))?
+    } else {
+        Vec::new()
+    };
+
+    let selection_set = (parse_selection_set(lexer))?;
+    let end = lexer.next_pos();
+
+    Ok(Spanning::new(start, end, Operation {
+        operation_type,
+        name,
+        variable_definitions,
+        selection_set,
+    }))
+}
+
+fn parse_fragment(lexer: &mut Lexer) -> Result<Spanning<Fragment>, ParseError> {
+    let start = lexer.next_pos();
+    (lexer.expect_name())?; // "fragment"
+    let name = (lexer.expect_name())?;
+    let on = (lexer.expect_name())?;
+    if on.item != "on" {
+        return Err(ParseError("Expected \"on\"".to_owned(), on.start));
+    }
+    let type_condition = (lexer.expect_name())?;
+    let selection_set = (parse_selection_set(lexer))?;
+    let end = lexer.next_pos();
+    Ok(Spanning::new(start, end, Fragment {
+        name,
+        type_condition,
+        selection_set,
+    }))
+}
+
+fn parse_variable_definitions(lexer: &mut Lexer)
+    -> Result<Vec<(Spanning<String>, VariableDefinition)>, ParseError> {
+    (lexer.expect_punct('('))?;
+    let mut defs = Vec::new();
+    while !lexer.is_punct(')') {
+        let dollar = lexer.next_pos();
+        (lexer.expect_punct('$'))?;
+        let name = (lexer.expect_name())?;
+        let name = Spanning::new(dollar, name.end, name.item);
+        (lexer.expect_punct(':'))?;
+        let var_type = (parse_type(lexer))?;
+        let default_value = if lexer.is_punct('=') {
+            (lexer.expect_punct('='))?;
+            Some((parse_value(lexer, true))?)
+        } else {
+            None
+        };
+        defs.push((name, VariableDefinition {
+            var_type,
+            default_value,
+        }));
+    }
+    (lexer.expect_punct(')')?);
+    Ok(defs)
+}
+
+fn parse_type(lexer: &mut Lexer) -> Result<Spanning<Type>, ParseError> {
+    let start = lexer.next_pos();
+    let inner = if lexer.is_punct('[') {
+        (lexer.expect_punct('['))?;
+        let inner = (parse_type(lexer))?;
+        (lexer.expect_punct(']'))?;
+        Type::List(Box::new(inner.item))
+    } else {
+        let name = (lexer.expect_name())?;
+        Type::Named(name.item)
+    };
+
+    let ty = if lexer.is_punct('!') {
+        (lexer.expect_punct('!'))?;
+        match inner {
+            Type::Named(n) => Type::NonNullNamed(n),
+            Type::List(l) => Type::NonNullList(l),
+            other => other,
+        }
+    } else {
+        inner
+    };
+
+    let end = lexer.next_pos();
+    Ok(Spanning::new(start, end, ty))
+}
+
+fn parse_selection_set(lexer: &mut Lexer) -> Result<Vec<Selection>, ParseError> {
+    (lexer.expect_punct('{'))?;
+    let mut selections = Vec::new();
+    while !lexer.is_punct('}') {
+        selections.push((parse_selection(lexer))?);
+    }
+    (lexer.expect_punct('}'))?;
+    Ok(selections)
+}
+
+fn parse_selection(lexer: &mut Lexer) -> Result<Selection, ParseError> {
+    if let Some(&Spanning { item: Token::Spread, .. }) = lexer.peek() {
+        return parse_fragment_selection(lexer);
+    }
+    parse_field(lexer).map(Selection::Field)
+}
+
+fn parse_fragment_selection(lexer: &mut Lexer) -> Result<Selection, ParseError> {
+    let start = lexer.next_pos();
+    (lexer.bump())?; // spread
+
+    // Inline fragment with a type condition.
+    if lexer.is_name("on") {
+        (lexer.expect_name())?;
+        let type_condition = (lexer.expect_name())?;
+        let directives = (parse_directives(lexer))?;
+        let selection_set = (parse_selection_set(lexer))?;
+        let end = lexer.next_pos();
+        return Ok(Selection::InlineFragment(Spanning::new(start, end, InlineFragment {
+            type_condition: Some(type_condition),
+            directives,
+            selection_set,
+        })));
+    }
+
+    // Inline fragment without a type condition.
+    if lexer.is_punct('{') || lexer.is_punct('@') {
+        let directives = (parse_directives(lexer))?;
+        let selection_set = (parse_selection_set(lexer))?;
+        let end = lexer.next_pos();
+        return Ok(Selection::InlineFragment(Spanning::new(start, end, InlineFragment {
+            type_condition: None,
+            directives,
+            selection_set,
+        })));
+    }
+
+    // Fragment spread.
+    let name = (lexer.expect_name())?;
+    let directives = (parse_directives(lexer))?;
+    let end = lexer.next_pos();
+    Ok(Selection::FragmentSpread(Spanning::new(start, end, FragmentSpread {
+        name,
+        directives,
+    })))
+}
+
+fn parse_field(lexer: &mut Lexer) -> Result<Spanning<Field>, ParseError> {
+    let start = lexer.next_pos();
+    let mut name = (lexer.expect_name())?;
+    let mut alias = None;
+
+    if lexer.is_punct(':') {
+        (lexer.expect_punct(':'))?;
+        alias = Some(name);
+        name = (lexer.expect_name())?;
+    }
+
+    let arguments = if lexer.is_punct('(') {
+        Some((parse_arguments(lexer))?)
+    } else {
+        None
+    };
+
+    let directives = (parse_directives(lexer))?;
+
+    let selection_set = if lexer.is_punct('{') {
+        Some((parse_selection_set(lexer))?)
+    } else {
+        None
+    };
+
+    let end = lexer.next_pos();
+    Ok(Spanning::new(start, end, Field {
+        alias,
+        name,
+        arguments,
+        directives,
+        selection_set,
+    }))
+}
+
+fn parse_arguments(lexer: &mut Lexer) -> Result<Spanning<Arguments>, ParseError> {
+    let start = lexer.next_pos();
+    (lexer.expect_punct('('))?;
+    let mut items = Vec::new();
+    while !lexer.is_punct(')') {
+        let name = (lexer.expect_name())?;
+        (lexer.expect_punct(':'))?;
+        let value = (parse_value(lexer, false))?;
+        items.push((name, value));
+    }
+    (lexer.expect_punct(')')?);
+    let end = lexer.next_pos();
+    Ok(Spanning::new(start, end, Arguments { items }))
+}
+
+fn parse_directives(lexer: &mut Lexer) -> Result<Option<Vec<Spanning<Directive>>>, ParseError> {
+    if !lexer.is_punct('@') {
+        return Ok(None);
+    }
+    let mut directives = Vec::new();
+    while lexer.is_punct('@') {
+        let start = lexer.next_pos();
+        (lexer.expect_punct('@'))?;
+        let name = (lexer.expect_name())?;
+        let arguments = if lexer.is_punct('(') {
+            Some((parse_arguments(lexer))?)
+        } else {
+            None
+        };
+        let end = lexer.next_pos();
+        directives.push(Spanning::new(start, end, Directive {
+            name,
+            arguments,
+        }));
+    }
+    Ok(Some(directives))
+}
+
+fn parse_value(lexer: &mut Lexer, is_const: bool) -> Result<Spanning<InputValue>, ParseError> {
+    let start = lexer.next_pos();
+
+    if !is_const && lexer.is_punct('$') {
+        (lexer.expect_punct('$'))?;
+        let name = (lexer.expect_name())?;
+        return Ok(Spanning::new(start, name.end, InputValue::Variable(name.item)));
+    }
+
+    if lexer.is_punct('[') {
+        (lexer.expect_punct('['))?;
+        let mut items = Vec::new();
+        while !lexer.is_punct(']') {
+            items.push((parse_value(lexer, is_const))?);
+        }
+        (lexer.expect_punct(']'))?;
+        let end = lexer.next_pos();
+        return Ok(Spanning::new(start, end, InputValue::List(items)));
+    }
+
+    if lexer.is_punct('{') {
+        (lexer.expect_punct('{'))?;
+        let mut fields = Vec::new();
+        while !lexer.is_punct('}') {
+            let key = (lexer.expect_name())?;
+            (lexer.expect_punct(':'))?;
+            let value = (parse_value(lexer, is_const))?;
+            fields.push((key, value));
+        }
+        (lexer.expect_punct('}'))?;
+        let end = lexer.next_pos();
+        return Ok(Spanning::new(start, end, InputValue::Object(fields)));
+    }
+
+    let t = (lexer.bump())?;
+    let value = match t.item {
+        Token::Int(i) => InputValue::Int(i),
+        Token::Float(f) => InputValue::Float(f),
+        Token::Str(s) => InputValue::String(s),
+        Token::Name(ref n) if n == "true" => InputValue::Boolean(true),
+        Token::Name(ref n) if n == "false" => InputValue::Boolean(false),
+        Token::Name(ref n) if n == "null" => InputValue::Null,
+        Token::Name(n) => InputValue::Enum(n),
+        other => return Err(ParseError(format!("Unexpected token {:?}", other), t.start)),
+    };
+    Ok(Spanning::new(t.start, t.end, value))
+}
@@ -0,0 +1,362 @@
+//! An opt-in pagination subsystem implementing the [Relay Cursor
+//! Connections](https://relay.dev/graphql/connections.htm) spec on top of
+//! `graphql_object!`.
+//!
+//! [`Connection::from_slice`] (or the more general [`Connection::new`], which
+//! also accepts `last`/`before`) turns a `Vec<T>` into a `Connection<T>`
+//! field return value: cursors are opaque, base64-encoded offsets, decoded
+//! back to slice the next page.
+
+use ast::{Arguments, Selection};
+use executor::{resolve_selection_set, Executor};
+use schema::model::{Field, MetaType, Registry};
+use types::base::{GraphQLType, InputValueError};
+use value::Value;
+
+const CURSOR_PREFIX: &str = "arrayconnection:";
+
+fn encode_cursor(offset: usize) -> String {
+    base64_encode(format!("{}{}", CURSOR_PREFIX, offset).as_bytes())
+}
+
+fn decode_cursor(cursor: &str) -> Option<usize> {
+    let bytes = base64_decode(cursor)?;
+    let text = String::from_utf8(bytes).ok()?;
+    text.strip_prefix(CURSOR_PREFIX)?.parse().ok()
+}
+
+const BASE64_ALPHABET: &[u8] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+fn base64_encode(input: &[u8]) -> String {
+    let mut out = String::with_capacity(input.len().div_ceil(3) * 4);
+    let mut bits: u32 = 0;
+    let mut bit_count = 0;
+
+    for &byte in input {
+        bits = (bits << 8) | u32::from(byte);
+        bit_count += 8;
+        while bit_count >= 6 {
+            bit_count -= 6;
+            out.push(BASE64_ALPHABET[((bits >> bit_count) & 0x3F) as usize] as char);
+        }
+    }
+    if bit_count > 0 {
+        out.push(BASE64_ALPHABET[((bits << (6 - bit_count)) & 0x3F) as usize] as char);
+    }
+    while !out.len().is_multiple_of(4) {
+        out.push('=');
+    }
+    out
+}
+
+fn base64_decode(input: &str) -> Option<Vec<u8>> {
+    let mut out = Vec::new();
+    let mut bits: u32 = 0;
+    let mut bit_count = 0;
+
+    for c in input.bytes() {
+        if c == b'=' {
+            break;
+        }
+        let value = BASE64_ALPHABET.iter().position(|&b| b == c)? as u32;
+        bits = (bits << 6) | value;
+        bit_count += 6;
+        if bit_count >= 8 {
+            bit_count -= 8;
+            out.push(((bits >> bit_count) & 0xFF) as u8);
+        }
+    }
+    Some(out)
+}
+
+/// A single Relay edge: a node together with its opaque cursor.
+pub struct Edge<T> {
+    node: T,
+    cursor: String,
+}
+
+impl<T: GraphQLType> GraphQLType for Edge<T> {
+    fn name() -> Option<String> {
+        T::name().map(|n| format!("{}Edge", n))
+    }
+
+    fn meta(registry: &mut Registry) -> MetaType {
+        let node_type = <T as GraphQLType>::type_ref(registry);
+        let cursor_type = <String as GraphQLType>::type_ref(registry);
+        MetaType::Object {
+            name: Self::name().expect("Edge<T> requires T to be a named type"),
+            description: None,
+            fields: vec![
+                Field {
+                    name: "node".to_owned(),
+                    field_type: node_type,
+                    arguments: Vec::new(),
+                    description: None,
+                    deprecation_reason: None,
+                },
+                Field {
+                    name: "cursor".to_owned(),
+                    field_type: cursor_type,
+                    arguments: Vec::new(),
+                    description: None,
+                    deprecation_reason: None,
+                },
+            ],
+        }
+    }
+
+    fn resolve(&self, selection: Option<&[Selection]>, executor: &Executor) -> Value {
+        match selection {
+            Some(sel) => resolve_selection_set(
+                self,
+                &Self::name().expect("Edge<T> requires T to be a named type"),
+                sel,
+                executor,
+            ),
+            None => Value::null(),
+        }
+    }
+
+    fn resolve_field(&self, field: &str, _arguments: &Arguments, executor: &Executor) -> Value {
+        match field {
+            "node" => self.node.resolve(executor.current_selection_set(), executor),
+            "cursor" => self.cursor.resolve(executor.current_selection_set(), executor),
+            _ => Value::null(),
+        }
+    }
+}
+
+/// Pagination metadata accompanying a [`Connection`].
+pub struct PageInfo {
+    has_next_page: bool,
+    has_previous_page: bool,
+    start_cursor: Option<String>,
+    end_cursor: Option<String>,
+}
+
+impl GraphQLType for PageInfo {
+    fn name() -> Option<String> {
+        Some("PageInfo".to_owned())
+    }
+
+    fn meta(registry: &mut Registry) -> MetaType {
+        let bool_type = <bool as GraphQLType>::type_ref(registry);
+        let cursor_type = <Option<String> as GraphQLType>::type_ref(registry);
+        MetaType::Object {
+            name: "PageInfo".to_owned(),
+            description: None,
+            fields: vec![
+                Field {
+                    name: "hasNextPage".to_owned(),
+                    field_type: bool_type.clone(),
+                    arguments: Vec::new(),
+                    description: None,
+                    deprecation_reason: None,
+                },
+                Field {
+                    name: "hasPreviousPage".to_owned(),
+                    field_type: bool_type,
+                    arguments: Vec::new(),
+                    description: None,
+                    deprecation_reason: None,
+                },
+                Field {
+                    name: "startCursor".to_owned(),
+                    field_type: cursor_type.clone(),
+                    arguments: Vec::new(),
+                    description: None,
+                    deprecation_reason: None,
+                },
+                Field {
+                    name: "endCursor".to_owned(),
+                    field_type: cursor_type,
+                    arguments: Vec::new(),
+                    description: None,
+                    deprecation_reason: None,
+                },
+            ],
+        }
+    }
+
+    fn resolve(&self, selection: Option<&[Selection]>, executor: &Executor) -> Value {
+        match selection {
+            Some(sel) => resolve_selection_set(self, "PageInfo", sel, executor),
+            None => Value::null(),
+        }
+    }
+
+    fn resolve_field(&self, field: &str, _arguments: &Arguments, executor: &Executor) -> Value {
+        match field {
+            "hasNextPage" => self.has_next_page.resolve(executor.current_selection_set(), executor),
+            "hasPreviousPage" => self
+                .has_previous_page
+                .resolve(executor.current_selection_set(), executor),
+            "startCursor" => self.start_cursor.resolve(executor.current_selection_set(), executor),
+            "endCursor" => self.end_cursor.resolve(executor.current_selection_set(), executor),
+            _ => Value::null(),
+        }
+    }
+}
+
+/// A page of `T` nodes, paginated per the Relay Cursor Connections spec.
+///
+/// Build one from a full (in-memory) list of items with [`from_slice`] or the
+/// more general [`new`], which additionally supports `last`/`before`. Both
+/// return a structured [`InputValueError`] for a malformed cursor or a
+/// negative `first`/`last` — declare the field's return type as
+/// `Result<Connection<T>, InputValueError>` and the `GraphQLType` impl for
+/// `Result` reports it through the executor and nulls the field, rather than
+/// requiring the resolver to unwrap it.
+///
+/// [`from_slice`]: #method.from_slice
+/// [`new`]: #method.new
+pub struct Connection<T> {
+    edges: Vec<Edge<T>>,
+    page_info: PageInfo,
+}
+
+impl<T> Connection<T> {
+    /// Paginate `items` by `first`/`after`, the common forward-pagination case.
+    ///
+    /// Errors (via [`InputValueError::Custom`]) if `after` is not a cursor
+    /// this module produced, or `first` is negative.
+    pub fn from_slice(
+        items: Vec<T>,
+        first: Option<i32>,
+        after: Option<String>,
+    ) -> Result<Connection<T>, InputValueError> {
+        Connection::new(items, first, after, None, None)
+    }
+
+    /// Paginate `items` per the Relay spec's pagination algorithm, supporting
+    /// both forward (`first`/`after`) and backward (`last`/`before`)
+    /// arguments.
+    ///
+    /// Errors (via [`InputValueError::Custom`]) if `after`/`before` is not a
+    /// cursor this module produced, or `first`/`last` is negative.
+    pub fn new(
+        items: Vec<T>,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> Result<Connection<T>, InputValueError> {
+        let mut edges: Vec<Edge<T>> = items
+            .into_iter()
+            .enumerate()
+            .map(|(offset, node)| Edge {
+                node,
+                cursor: encode_cursor(offset),
+            })
+            .collect();
+
+        if let Some(ref cursor) = after {
+            let after_offset = decode_cursor(cursor).ok_or_else(|| {
+                InputValueError::Custom(format!("Invalid cursor for argument \"after\": {:?}", cursor))
+            })?;
+            edges.retain(|edge| decode_cursor(&edge.cursor).map(|o| o > after_offset).unwrap_or(false));
+        }
+        if let Some(ref cursor) = before {
+            let before_offset = decode_cursor(cursor).ok_or_else(|| {
+                InputValueError::Custom(format!("Invalid cursor for argument \"before\": {:?}", cursor))
+            })?;
+            edges.retain(|edge| decode_cursor(&edge.cursor).map(|o| o < before_offset).unwrap_or(false));
+        }
+
+        let mut has_next_page = false;
+        if let Some(first) = first {
+            if first < 0 {
+                return Err(InputValueError::Custom(format!(
+                    "Argument \"first\" must be a non-negative integer, got {}",
+                    first
+                )));
+            }
+            let first = first as usize;
+            if edges.len() > first {
+                has_next_page = true;
+                edges.truncate(first);
+            }
+        }
+
+        let mut has_previous_page = false;
+        if let Some(last) = last {
+            if last < 0 {
+                return Err(InputValueError::Custom(format!(
+                    "Argument \"last\" must be a non-negative integer, got {}",
+                    last
+                )));
+            }
+            let last = last as usize;
+            if edges.len() > last {
+                has_previous_page = true;
+                let split_at = edges.len() - last;
+                edges = edges.split_off(split_at);
+            }
+        }
+
+        let start_cursor = edges.first().map(|e| e.cursor.clone());
+        let end_cursor = edges.last().map(|e| e.cursor.clone());
+
+        Ok(Connection {
+            edges,
+            page_info: PageInfo {
+                has_next_page,
+                has_previous_page,
+                start_cursor,
+                end_cursor,
+            },
+        })
+    }
+}
+
+impl<T: GraphQLType> GraphQLType for Connection<T> {
+    fn name() -> Option<String> {
+        T::name().map(|n| format!("{}Connection", n))
+    }
+
+    fn meta(registry: &mut Registry) -> MetaType {
+        let edges_type = <Vec<Edge<T>> as GraphQLType>::type_ref(registry);
+        let page_info_type = <PageInfo as GraphQLType>::type_ref(registry);
+        MetaType::Object {
+            name: Self::name().expect("Connection<T> requires T to be a named type"),
+            description: None,
+            fields: vec![
+                Field {
+                    name: "edges".to_owned(),
+                    field_type: edges_type,
+                    arguments: Vec::new(),
+                    description: None,
+                    deprecation_reason: None,
+                },
+                Field {
+                    name: "pageInfo".to_owned(),
+                    field_type: page_info_type,
+                    arguments: Vec::new(),
+                    description: None,
+                    deprecation_reason: None,
+                },
+            ],
+        }
+    }
+
+    fn resolve(&self, selection: Option<&[Selection]>, executor: &Executor) -> Value {
+        match selection {
+            Some(sel) => resolve_selection_set(
+                self,
+                &Self::name().expect("Connection<T> requires T to be a named type"),
+                sel,
+                executor,
+            ),
+            None => Value::null(),
+        }
+    }
+
+    fn resolve_field(&self, field: &str, _arguments: &Arguments, executor: &Executor) -> Value {
+        match field {
+            "edges" => self.edges.resolve(executor.current_selection_set(), executor),
+            "pageInfo" => self.page_info.resolve(executor.current_selection_set(), executor),
+            _ => Value::null(),
+        }
+    }
+}
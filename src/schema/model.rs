@@ -0,0 +1,213 @@
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+use ast::Type;
+use types::base::GraphQLType;
+
+/// A single value of an enum type, as exposed through introspection.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EnumValue {
+    pub name: String,
+    pub description: Option<String>,
+    pub deprecation_reason: Option<String>,
+}
+
+/// A field argument definition.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Argument {
+    pub name: String,
+    pub arg_type: Type,
+    pub description: Option<String>,
+}
+
+/// A field definition on an object type.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Field {
+    pub name: String,
+    pub field_type: Type,
+    pub arguments: Vec<Argument>,
+    pub description: Option<String>,
+    pub deprecation_reason: Option<String>,
+}
+
+impl Field {
+    pub fn argument(&self, name: &str) -> Option<&Argument> {
+        self.arguments.iter().find(|a| a.name == name)
+    }
+}
+
+/// The introspection metadata describing a single named type.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MetaType {
+    Scalar {
+        name: String,
+        description: Option<String>,
+    },
+    Object {
+        name: String,
+        description: Option<String>,
+        fields: Vec<Field>,
+    },
+    Enum {
+        name: String,
+        description: Option<String>,
+        values: Vec<EnumValue>,
+    },
+    Union {
+        name: String,
+        description: Option<String>,
+        possible_types: Vec<String>,
+    },
+    /// Inserted while a type is mid-registration to break reference cycles.
+    Placeholder,
+}
+
+impl MetaType {
+    pub fn name(&self) -> Option<&str> {
+        match *self {
+            MetaType::Scalar { ref name, .. }
+            | MetaType::Object { ref name, .. }
+            | MetaType::Enum { ref name, .. }
+            | MetaType::Union { ref name, .. } => Some(name),
+            MetaType::Placeholder => None,
+        }
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        match *self {
+            MetaType::Scalar { ref description, .. }
+            | MetaType::Object { ref description, .. }
+            | MetaType::Enum { ref description, .. }
+            | MetaType::Union { ref description, .. } => description.as_ref().map(|s| &s[..]),
+            MetaType::Placeholder => None,
+        }
+    }
+
+    pub fn type_kind(&self) -> &'static str {
+        match *self {
+            MetaType::Scalar { .. } => "SCALAR",
+            MetaType::Object { .. } => "OBJECT",
+            MetaType::Enum { .. } => "ENUM",
+            MetaType::Union { .. } => "UNION",
+            MetaType::Placeholder => "SCALAR",
+        }
+    }
+
+    pub fn field(&self, name: &str) -> Option<&Field> {
+        match *self {
+            MetaType::Object { ref fields, .. } => fields.iter().find(|f| f.name == name),
+            _ => None,
+        }
+    }
+}
+
+/// A built-in or user-defined directive definition.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DirectiveType {
+    pub name: String,
+    pub locations: Vec<String>,
+    pub arguments: Vec<Argument>,
+}
+
+/// The built-in `@skip` and `@include` directives, registered on every
+/// schema so `__schema { directives { ... } }` reports them.
+fn builtin_directives() -> Vec<DirectiveType> {
+    let locations = || {
+        vec![
+            "FIELD".to_owned(),
+            "FRAGMENT_SPREAD".to_owned(),
+            "INLINE_FRAGMENT".to_owned(),
+        ]
+    };
+    let if_argument = || Argument {
+        name: "if".to_owned(),
+        arg_type: Type::NonNullNamed("Boolean".to_owned()),
+        description: None,
+    };
+
+    vec![
+        DirectiveType {
+            name: "skip".to_owned(),
+            locations: locations(),
+            arguments: vec![if_argument()],
+        },
+        DirectiveType {
+            name: "include".to_owned(),
+            locations: locations(),
+            arguments: vec![if_argument()],
+        },
+    ]
+}
+
+/// Accumulates type metadata while a schema is being built.
+pub struct Registry {
+    pub types: HashMap<String, MetaType>,
+}
+
+impl Default for Registry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Registry {
+    pub fn new() -> Registry {
+        Registry {
+            types: HashMap::new(),
+        }
+    }
+
+    /// Register `T` (if not already present) and return a reference to it.
+    pub fn get_type<T: GraphQLType>(&mut self) -> Type {
+        T::type_ref(self)
+    }
+}
+
+/// The fully-built schema, ready for execution and introspection.
+pub struct SchemaType {
+    pub types: HashMap<String, MetaType>,
+    pub query_type_name: String,
+    pub mutation_type_name: String,
+    pub directives: Vec<DirectiveType>,
+}
+
+impl SchemaType {
+    pub fn type_by_name(&self, name: &str) -> Option<&MetaType> {
+        self.types.get(name)
+    }
+}
+
+/// The root of an executable schema, pairing the query and mutation roots with
+/// the built type registry.
+pub struct RootNode<QueryT, MutationT> {
+    pub query: QueryT,
+    pub mutation: MutationT,
+    pub schema: SchemaType,
+    phantom: PhantomData<()>,
+}
+
+impl<QueryT, MutationT> RootNode<QueryT, MutationT>
+where
+    QueryT: GraphQLType,
+    MutationT: GraphQLType,
+{
+    pub fn new(query: QueryT, mutation: MutationT) -> RootNode<QueryT, MutationT> {
+        let mut registry = Registry::new();
+        registry.get_type::<QueryT>();
+        registry.get_type::<MutationT>();
+
+        let schema = SchemaType {
+            types: registry.types,
+            query_type_name: QueryT::name().unwrap_or_else(String::new),
+            mutation_type_name: MutationT::name().unwrap_or_else(String::new),
+            directives: builtin_directives(),
+        };
+
+        RootNode {
+            query,
+            mutation,
+            schema,
+            phantom: PhantomData,
+        }
+    }
+}
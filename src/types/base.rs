@@ -0,0 +1,308 @@
+//! The core `GraphQLType` trait and implementations for the built-in scalars
+//! and wrapper types.
+
+use std::fmt;
+
+use ast::{Arguments, InputValue, Selection};
+use executor::Executor;
+use schema::model::{MetaType, Registry};
+use value::Value;
+use ast::Type;
+
+/// Trait implemented by every type exposed in a GraphQL schema.
+///
+/// Named types (objects, enums, unions, scalars) return their name from
+/// [`name`](#method.name) and describe themselves through
+/// [`meta`](#method.meta). Wrapper types (`Option`, `Vec`) are anonymous and
+/// override [`type_ref`](#method.type_ref) instead.
+pub trait GraphQLType: Sized {
+    /// The name of a named type, or `None` for wrapper types.
+    fn name() -> Option<String>;
+
+    /// Build the introspection metadata for this type, registering any types
+    /// it refers to in the process.
+    fn meta(registry: &mut Registry) -> MetaType;
+
+    /// The type reference used when this type appears as a field or argument
+    /// type, registering the underlying named type as a side effect.
+    fn type_ref(registry: &mut Registry) -> Type {
+        let name = Self::name().expect("Only named types have a default type_ref");
+        if !registry.types.contains_key(&name) {
+            registry.types.insert(name.clone(), MetaType::Placeholder);
+            let meta = Self::meta(registry);
+            registry.types.insert(name.clone(), meta);
+        }
+        Type::NonNullNamed(name)
+    }
+
+    /// Resolve this value against a selection set, producing an output value.
+    fn resolve(&self, selection: Option<&[Selection]>, executor: &Executor) -> Value;
+
+    /// Resolve a single field on an object type.
+    #[allow(unused_variables)]
+    fn resolve_field(&self, field: &str, arguments: &Arguments, executor: &Executor) -> Value {
+        Value::null()
+    }
+
+    /// The name of the concrete type this value represents — used for
+    /// `__typename` and union/interface dispatch.
+    fn concrete_type_name(&self) -> String {
+        Self::name().unwrap_or_else(String::new)
+    }
+}
+
+/// Why coercing an [`InputValue`] into a Rust type failed.
+///
+/// Carrying the offending value (and, for enums, the accepted names) lets
+/// callers match on the failure kind instead of parsing a rendered message —
+/// see [`RuleError::kind`](../../validation/struct.RuleError.html#method.kind)
+/// for where variable coercion surfaces this.
+#[derive(Clone, Debug, PartialEq)]
+pub enum InputValueError {
+    /// The value was not of the type being coerced into.
+    ExpectedType(InputValue),
+    /// An enum or string value didn't match any of the type's declared names.
+    UnknownEnumValue {
+        got: InputValue,
+        expected_one_of: Vec<String>,
+    },
+    /// Any other coercion failure, described in prose.
+    Custom(String),
+}
+
+impl InputValueError {
+    /// Render this error using the name of the type the value was coerced
+    /// against, matching the wording used by variable-coercion diagnostics.
+    pub fn message(&self, type_name: &str) -> String {
+        match *self {
+            InputValueError::ExpectedType(ref value) => {
+                format!("Expected \"{}\", found {}.", type_name, value)
+            }
+            InputValueError::UnknownEnumValue {
+                ref got,
+                ref expected_one_of,
+            } => format!(
+                "Invalid value for enum \"{}\": found {}, expected one of {}.",
+                type_name,
+                got,
+                expected_one_of.join(", ")
+            ),
+            InputValueError::Custom(ref message) => message.clone(),
+        }
+    }
+}
+
+/// Rendering used when an `InputValueError` is reported as a field-resolution
+/// error, where no single expected type name is in scope — see
+/// `impl GraphQLType for Result<T, InputValueError>` below.
+impl fmt::Display for InputValueError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            InputValueError::ExpectedType(ref value) => write!(f, "Invalid value: found {}", value),
+            InputValueError::UnknownEnumValue {
+                ref got,
+                ref expected_one_of,
+            } => write!(
+                f,
+                "Invalid value: found {}, expected one of {}",
+                got,
+                expected_one_of.join(", ")
+            ),
+            InputValueError::Custom(ref message) => write!(f, "{}", message),
+        }
+    }
+}
+
+/// Conversion from an input value (query literal or variable) into a Rust type.
+pub trait FromInputValue: Sized {
+    fn from_input_value(value: &InputValue) -> Result<Self, InputValueError>;
+}
+
+macro_rules! scalar {
+    ($t:ty, $name:expr, $variant:ident) => {
+        impl GraphQLType for $t {
+            fn name() -> Option<String> {
+                Some($name.to_owned())
+            }
+            fn meta(_registry: &mut Registry) -> MetaType {
+                MetaType::Scalar {
+                    name: $name.to_owned(),
+                    description: None,
+                }
+            }
+            fn resolve(&self, _selection: Option<&[Selection]>, _executor: &Executor) -> Value {
+                Value::$variant(self.clone())
+            }
+        }
+    };
+}
+
+scalar!(String, "String", String);
+scalar!(bool, "Boolean", Boolean);
+
+impl GraphQLType for i32 {
+    fn name() -> Option<String> {
+        Some("Int".to_owned())
+    }
+    fn meta(_registry: &mut Registry) -> MetaType {
+        MetaType::Scalar {
+            name: "Int".to_owned(),
+            description: None,
+        }
+    }
+    fn resolve(&self, _selection: Option<&[Selection]>, _executor: &Executor) -> Value {
+        Value::int(*self as i64)
+    }
+}
+
+impl GraphQLType for f64 {
+    fn name() -> Option<String> {
+        Some("Float".to_owned())
+    }
+    fn meta(_registry: &mut Registry) -> MetaType {
+        MetaType::Scalar {
+            name: "Float".to_owned(),
+            description: None,
+        }
+    }
+    fn resolve(&self, _selection: Option<&[Selection]>, _executor: &Executor) -> Value {
+        Value::float(*self)
+    }
+}
+
+impl GraphQLType for &str {
+    fn name() -> Option<String> {
+        Some("String".to_owned())
+    }
+    fn meta(_registry: &mut Registry) -> MetaType {
+        MetaType::Scalar {
+            name: "String".to_owned(),
+            description: None,
+        }
+    }
+    fn resolve(&self, _selection: Option<&[Selection]>, _executor: &Executor) -> Value {
+        Value::string(self)
+    }
+}
+
+impl<T: GraphQLType> GraphQLType for Option<T> {
+    fn name() -> Option<String> {
+        None
+    }
+    fn meta(registry: &mut Registry) -> MetaType {
+        T::meta(registry)
+    }
+    fn type_ref(registry: &mut Registry) -> Type {
+        match T::type_ref(registry) {
+            Type::NonNullNamed(n) => Type::Named(n),
+            Type::NonNullList(l) => Type::List(l),
+            other => other,
+        }
+    }
+    fn resolve(&self, selection: Option<&[Selection]>, executor: &Executor) -> Value {
+        match *self {
+            Some(ref v) => v.resolve(selection, executor),
+            None => Value::null(),
+        }
+    }
+}
+
+/// Lets a `graphql_object!` field resolver return
+/// `Result<T, InputValueError>` directly: `Ok` resolves `T` as normal, while
+/// `Err` records the error on the executor and nulls out just this field,
+/// rather than the panic-on-`.expect()` a resolver would otherwise need to
+/// report a bad argument.
+impl<T: GraphQLType> GraphQLType for Result<T, InputValueError> {
+    fn name() -> Option<String> {
+        None
+    }
+    fn meta(registry: &mut Registry) -> MetaType {
+        T::meta(registry)
+    }
+    fn type_ref(registry: &mut Registry) -> Type {
+        // An `Err` resolves to `null`, so advertise the same nullable type
+        // `Option<T>` would, rather than claiming `T`'s non-null type while
+        // being able to resolve to null.
+        match T::type_ref(registry) {
+            Type::NonNullNamed(n) => Type::Named(n),
+            Type::NonNullList(l) => Type::List(l),
+            other => other,
+        }
+    }
+    fn resolve(&self, selection: Option<&[Selection]>, executor: &Executor) -> Value {
+        match *self {
+            Ok(ref value) => value.resolve(selection, executor),
+            Err(ref err) => {
+                executor.push_error(err.to_string());
+                Value::null()
+            }
+        }
+    }
+}
+
+impl<T: GraphQLType> GraphQLType for Vec<T> {
+    fn name() -> Option<String> {
+        None
+    }
+    fn meta(registry: &mut Registry) -> MetaType {
+        T::meta(registry)
+    }
+    fn type_ref(registry: &mut Registry) -> Type {
+        Type::NonNullList(Box::new(T::type_ref(registry)))
+    }
+    fn resolve(&self, selection: Option<&[Selection]>, executor: &Executor) -> Value {
+        Value::list(self.iter().map(|v| v.resolve(selection, executor)).collect())
+    }
+}
+
+impl FromInputValue for String {
+    fn from_input_value(value: &InputValue) -> Result<String, InputValueError> {
+        match *value {
+            InputValue::String(ref s) => Ok(s.clone()),
+            ref other => Err(InputValueError::ExpectedType(other.clone())),
+        }
+    }
+}
+
+impl FromInputValue for bool {
+    fn from_input_value(value: &InputValue) -> Result<bool, InputValueError> {
+        match *value {
+            InputValue::Boolean(b) => Ok(b),
+            ref other => Err(InputValueError::ExpectedType(other.clone())),
+        }
+    }
+}
+
+impl FromInputValue for i32 {
+    fn from_input_value(value: &InputValue) -> Result<i32, InputValueError> {
+        match *value {
+            InputValue::Int(i) => Ok(i as i32),
+            ref other => Err(InputValueError::ExpectedType(other.clone())),
+        }
+    }
+}
+
+impl FromInputValue for f64 {
+    fn from_input_value(value: &InputValue) -> Result<f64, InputValueError> {
+        match *value {
+            InputValue::Float(f) => Ok(f),
+            InputValue::Int(i) => Ok(i as f64),
+            ref other => Err(InputValueError::ExpectedType(other.clone())),
+        }
+    }
+}
+
+impl<T: FromInputValue> FromInputValue for Option<T> {
+    fn from_input_value(value: &InputValue) -> Result<Option<T>, InputValueError> {
+        match *value {
+            InputValue::Null => Ok(None),
+            ref other => T::from_input_value(other).map(Some),
+        }
+    }
+}
+
+/// Helper used by the generated object resolvers to resolve the value returned
+/// from a field body against the field's selection set.
+pub fn resolve_value<V: GraphQLType>(value: V, executor: &Executor) -> Value {
+    value.resolve(executor.current_selection_set(), executor)
+}
@@ -0,0 +1,48 @@
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+use ast::Selection;
+use executor::Executor;
+use schema::model::{MetaType, Registry};
+use types::base::GraphQLType;
+use value::Value;
+
+/// A mutation root that exposes no fields.
+///
+/// Useful as the mutation type of a read-only schema: `RootNode::new(query,
+/// EmptyMutation::new())`.
+pub struct EmptyMutation<T> {
+    phantom: PhantomData<T>,
+}
+
+impl<T> Default for EmptyMutation<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> EmptyMutation<T> {
+    pub fn new() -> EmptyMutation<T> {
+        EmptyMutation {
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> GraphQLType for EmptyMutation<T> {
+    fn name() -> Option<String> {
+        Some("_EmptyMutation".to_owned())
+    }
+
+    fn meta(_registry: &mut Registry) -> MetaType {
+        MetaType::Object {
+            name: "_EmptyMutation".to_owned(),
+            description: None,
+            fields: Vec::new(),
+        }
+    }
+
+    fn resolve(&self, _selection: Option<&[Selection]>, _executor: &Executor) -> Value {
+        Value::Object(HashMap::new())
+    }
+}
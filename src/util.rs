@@ -0,0 +1,20 @@
+/// Convert a Rust `snake_case` identifier into GraphQL `camelCase`.
+pub fn to_camel_case(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    let mut uppercase_next = false;
+
+    for (i, c) in s.chars().enumerate() {
+        if c == '_' {
+            if i != 0 {
+                uppercase_next = true;
+            }
+        } else if uppercase_next {
+            result.extend(c.to_uppercase());
+            uppercase_next = false;
+        } else {
+            result.push(c);
+        }
+    }
+
+    result
+}
@@ -0,0 +1,238 @@
+//! Query validation: argument literals and variable coercion.
+
+use std::collections::HashMap;
+
+use ast::{InputValue, Operation, Selection, Type};
+use parser::SourcePosition;
+use schema::model::{MetaType, SchemaType};
+use executor::Variables;
+use types::base::InputValueError;
+
+/// A validation error, carrying a message and the source positions it relates
+/// to.
+///
+/// Errors raised while coercing a variable's value additionally carry the
+/// structured [`InputValueError`] they were built from, via [`kind`], so
+/// callers can match on the failure instead of parsing the rendered message.
+///
+/// [`kind`]: #method.kind
+#[derive(Clone, Debug, PartialEq)]
+pub struct RuleError {
+    message: String,
+    locations: Vec<SourcePosition>,
+    kind: Option<InputValueError>,
+}
+
+impl RuleError {
+    pub fn new(message: &str, locations: &[SourcePosition]) -> RuleError {
+        RuleError {
+            message: message.to_owned(),
+            locations: locations.to_vec(),
+            kind: None,
+        }
+    }
+
+    fn from_input_value_error(
+        var_name: &str,
+        type_name: &str,
+        err: InputValueError,
+        locations: &[SourcePosition],
+    ) -> RuleError {
+        RuleError {
+            message: format!(
+                "Variable \"${}\" got invalid value. {}",
+                var_name,
+                err.message(type_name)
+            ),
+            locations: locations.to_vec(),
+            kind: Some(err),
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn locations(&self) -> &[SourcePosition] {
+        &self.locations
+    }
+
+    /// The structured reason for this error, when it was raised while
+    /// coercing a variable's value.
+    pub fn kind(&self) -> Option<&InputValueError> {
+        self.kind.as_ref()
+    }
+}
+
+/// Validate that the literal arguments in a selection set are of the correct
+/// type, recording any errors.
+pub fn validate_arguments(
+    schema: &SchemaType,
+    type_name: &str,
+    selections: &[Selection],
+    errors: &mut Vec<RuleError>,
+) {
+    let meta = schema.type_by_name(type_name);
+
+    for selection in selections {
+        if let Selection::Field(ref spanning) = *selection {
+            let field = &spanning.item;
+            let field_def = meta.and_then(|m| m.field(&field.name.item));
+
+            if let (Some(args), Some(field_def)) = (field.arguments.as_ref(), field_def) {
+                for (arg_name, arg_value) in &args.item.items {
+                    if arg_value.item.is_variable() {
+                        continue;
+                    }
+                    if let Some(arg_def) = field_def.argument(&arg_name.item) {
+                        if !is_valid_literal(schema, &arg_def.arg_type, &arg_value.item) {
+                            errors.push(RuleError::new(
+                                &format!(
+                                    "Invalid value for argument \"{}\", expected type \"{}\"",
+                                    arg_name.item, arg_def.arg_type
+                                ),
+                                &[arg_value.start],
+                            ));
+                        }
+                    }
+                }
+            }
+
+            if let Some(ref sub) = field.selection_set {
+                let next = field_def
+                    .map(|f| f.field_type.innermost_name().to_owned())
+                    .unwrap_or_default();
+                validate_arguments(schema, &next, sub, errors);
+            }
+        }
+    }
+}
+
+/// Build the coerced variable map for an operation, recording any coercion
+/// errors.
+pub fn coerce_variables(
+    schema: &SchemaType,
+    operation: &Operation,
+    supplied: &Variables,
+    errors: &mut Vec<RuleError>,
+) -> Variables {
+    let mut coerced = HashMap::new();
+
+    for (name, definition) in &operation.variable_definitions {
+        let var_type = &definition.var_type.item;
+        let type_name = var_type.innermost_name();
+
+        match supplied.get(&name.item) {
+            Some(value) => match validate_variable_value(schema, var_type, value) {
+                Ok(()) => {
+                    coerced.insert(name.item.clone(), value.clone());
+                }
+                Err(err) => errors.push(RuleError::from_input_value_error(
+                    &name.item,
+                    type_name,
+                    err,
+                    &[name.start],
+                )),
+            },
+            None => match definition.default_value {
+                Some(ref default) => match validate_variable_value(schema, var_type, &default.item) {
+                    Ok(()) => {
+                        coerced.insert(name.item.clone(), default.item.clone());
+                    }
+                    Err(err) => errors.push(RuleError::from_input_value_error(
+                        &name.item,
+                        type_name,
+                        err,
+                        &[name.start],
+                    )),
+                },
+                None => {
+                    if is_non_null(var_type) {
+                        errors.push(RuleError::new(
+                            &format!(
+                                "Variable \"${}\" of required type \"{}\" was not provided.",
+                                name.item, var_type
+                            ),
+                            &[name.start],
+                        ));
+                    } else {
+                        coerced.insert(name.item.clone(), InputValue::Null);
+                    }
+                }
+            },
+        }
+    }
+
+    coerced
+}
+
+fn is_non_null(var_type: &Type) -> bool {
+    matches!(*var_type, Type::NonNullNamed(_) | Type::NonNullList(_))
+}
+
+/// Validate a variable value. Enums accept the equivalent string as per the
+/// spec's variable coercion rules.
+fn validate_variable_value(
+    schema: &SchemaType,
+    var_type: &Type,
+    value: &InputValue,
+) -> Result<(), InputValueError> {
+    let type_name = var_type.innermost_name();
+
+    match schema.type_by_name(type_name) {
+        Some(MetaType::Enum { values, .. }) => match *value {
+            InputValue::Enum(ref s) | InputValue::String(ref s) => {
+                if values.iter().any(|v| &v.name == s) {
+                    Ok(())
+                } else {
+                    Err(InputValueError::UnknownEnumValue {
+                        got: value.clone(),
+                        expected_one_of: values.iter().map(|v| v.name.clone()).collect(),
+                    })
+                }
+            }
+            InputValue::Null => Ok(()),
+            _ => Err(InputValueError::ExpectedType(value.clone())),
+        },
+        Some(MetaType::Scalar { name, .. }) => {
+            if scalar_matches(name, value) {
+                Ok(())
+            } else {
+                Err(InputValueError::ExpectedType(value.clone()))
+            }
+        }
+        _ => Ok(()),
+    }
+}
+
+fn scalar_matches(name: &str, value: &InputValue) -> bool {
+    matches!(
+        (name, value),
+        (_, &InputValue::Null)
+            | ("String", &InputValue::String(_))
+            | ("Boolean", &InputValue::Boolean(_))
+            | ("Int", &InputValue::Int(_))
+            | ("Float", &InputValue::Float(_))
+            | ("Float", &InputValue::Int(_))
+    )
+}
+
+/// Whether a literal (non-variable) value is valid for the given input type.
+/// Unlike variable coercion, enum inputs must be written as enum values, not
+/// strings.
+fn is_valid_literal(schema: &SchemaType, arg_type: &Type, value: &InputValue) -> bool {
+    if value.is_null() {
+        return true;
+    }
+
+    let type_name = arg_type.innermost_name();
+
+    match schema.type_by_name(type_name) {
+        Some(MetaType::Enum { values, .. }) => match *value {
+            InputValue::Enum(ref s) => values.iter().any(|v| &v.name == s),
+            _ => false,
+        },
+        Some(MetaType::Scalar { name, .. }) => scalar_matches(name, value),
+        _ => true,
+    }
+}
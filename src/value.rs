@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+use std::iter::FromIterator;
+
+/// A resolved output value, produced by executing a query against the schema.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Null,
+    Int(i64),
+    Float(f64),
+    String(String),
+    Boolean(bool),
+    List(Vec<Value>),
+    Object(HashMap<String, Value>),
+}
+
+impl Value {
+    pub fn null() -> Value {
+        Value::Null
+    }
+
+    pub fn int(i: i64) -> Value {
+        Value::Int(i)
+    }
+
+    pub fn float(f: f64) -> Value {
+        Value::Float(f)
+    }
+
+    pub fn string<T: AsRef<str>>(s: T) -> Value {
+        Value::String(s.as_ref().to_owned())
+    }
+
+    pub fn boolean(b: bool) -> Value {
+        Value::Boolean(b)
+    }
+
+    pub fn list(l: Vec<Value>) -> Value {
+        Value::List(l)
+    }
+
+    pub fn object(o: Object) -> Value {
+        Value::Object(o.0)
+    }
+
+    pub fn as_object_value(&self) -> Option<&HashMap<String, Value>> {
+        match *self {
+            Value::Object(ref o) => Some(o),
+            _ => None,
+        }
+    }
+
+    pub fn as_list_value(&self) -> Option<&Vec<Value>> {
+        match *self {
+            Value::List(ref l) => Some(l),
+            _ => None,
+        }
+    }
+
+    pub fn as_string_value(&self) -> Option<&str> {
+        match *self {
+            Value::String(ref s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+/// Helper builder for object values.
+///
+/// Exists so that `vec![("field", Value::string("x"))].into_iter().collect()`
+/// can be passed directly to `Value::object` with borrowed string keys.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Object(HashMap<String, Value>);
+
+impl<'a> FromIterator<(&'a str, Value)> for Object {
+    fn from_iter<T: IntoIterator<Item = (&'a str, Value)>>(iter: T) -> Object {
+        Object(iter.into_iter().map(|(k, v)| (k.to_owned(), v)).collect())
+    }
+}
+
+impl FromIterator<(String, Value)> for Object {
+    fn from_iter<T: IntoIterator<Item = (String, Value)>>(iter: T) -> Object {
+        Object(iter.into_iter().collect())
+    }
+}